@@ -0,0 +1,278 @@
+// Hex dump for terminals, colored with ANSI escape sequences the same way the hex dump of the HTML
+// report is highlighted
+
+use crate::elf::parser::{ParsedElf, RangeType};
+use crate::utils;
+use std::io::{self, Write};
+use std::num::NonZeroUsize;
+
+const DEFAULT_COLUMNS: usize = 16;
+const RESET: &str = "\x1b[0m";
+
+pub struct HexdumpOptions {
+    // part of the file to dump, widened to whole rows
+    pub start: usize,
+    pub end: Option<usize>,
+    // print what the colors mean before the dump
+    pub legend: bool,
+    // bytes per row, up to MAX_COLUMNS
+    pub columns: NonZeroUsize,
+}
+
+impl Default for HexdumpOptions {
+    fn default() -> HexdumpOptions {
+        HexdumpOptions {
+            start: 0,
+            end: None,
+            legend: true,
+            columns: NonZeroUsize::new(DEFAULT_COLUMNS).expect("default columns aren't zero"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Highlight {
+    Ident,
+    FileHeader,
+    ProgramHeader,
+    SectionHeader,
+    Segment,
+    Section,
+    SegmentAndSection,
+}
+
+// same order as the legend of the HTML report
+const LEGEND: [Highlight; 7] = [
+    Highlight::Ident,
+    Highlight::FileHeader,
+    Highlight::ProgramHeader,
+    Highlight::SectionHeader,
+    Highlight::Segment,
+    Highlight::Section,
+    Highlight::SegmentAndSection,
+];
+
+impl Highlight {
+    fn from_range(range_type: &RangeType) -> Option<Highlight> {
+        match range_type {
+            RangeType::Ident => Some(Highlight::Ident),
+            RangeType::FileHeader => Some(Highlight::FileHeader),
+            RangeType::ProgramHeader(_) => Some(Highlight::ProgramHeader),
+            RangeType::SectionHeader(_) => Some(Highlight::SectionHeader),
+            RangeType::Segment(_) => Some(Highlight::Segment),
+            RangeType::Section(_) => Some(Highlight::Section),
+            _ => None,
+        }
+    }
+
+    // background colors of the light theme of style.css. Overlaps are striped there, which a
+    // terminal can't do.
+    fn color(self) -> (u8, u8, u8) {
+        match self {
+            Highlight::Ident => (0xee, 0x99, 0x99),
+            Highlight::FileHeader => (0x99, 0x99, 0xee),
+            Highlight::ProgramHeader => (0xee, 0xbb, 0x99),
+            Highlight::SectionHeader => (0x99, 0xbb, 0xee),
+            Highlight::Segment => (0xff, 0x99, 0x99),
+            Highlight::Section => (0xff, 0x99, 0xff),
+            Highlight::SegmentAndSection => (0xff, 0x99, 0xcc),
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Highlight::Ident => "ELF Identification",
+            Highlight::FileHeader => "ELF Header",
+            Highlight::ProgramHeader => "Program Header",
+            Highlight::SectionHeader => "Section Header",
+            Highlight::Segment => "Segment",
+            Highlight::Section => "Section",
+            Highlight::SegmentAndSection => "Segment & Section overlap",
+        }
+    }
+
+    fn escape(self) -> String {
+        let (r, g, b) = self.color();
+
+        // black text, as the backgrounds are light
+        format!("\x1b[38;2;0;0;0;48;2;{};{};{}m", r, g, b)
+    }
+}
+
+// Headers are within segments and the identification is within the file header, so the innermost
+// of the ranges covering a byte wins
+fn classify(active: &[(usize, Highlight)]) -> Option<Highlight> {
+    let has = |highlight| active.iter().any(|&(_, h)| h == highlight);
+
+    for highlight in [
+        Highlight::Ident,
+        Highlight::FileHeader,
+        Highlight::ProgramHeader,
+        Highlight::SectionHeader,
+    ] {
+        if has(highlight) {
+            return Some(highlight);
+        }
+    }
+
+    match (has(Highlight::Segment), has(Highlight::Section)) {
+        (true, true) => Some(Highlight::SegmentAndSection),
+        (false, true) => Some(Highlight::Section),
+        (true, false) => Some(Highlight::Segment),
+        (false, false) => None,
+    }
+}
+
+// Appends an escape sequence if the color changes
+fn set_highlight(line: &mut String, current: &mut Option<Highlight>, wanted: Option<Highlight>) {
+    if *current == wanted {
+        return;
+    }
+
+    match wanted {
+        Some(highlight) => line.push_str(&highlight.escape()),
+        None => line.push_str(RESET),
+    }
+
+    *current = wanted;
+}
+
+fn write_legend(o: &mut dyn Write) -> io::Result<()> {
+    let items: Vec<String> = LEGEND
+        .iter()
+        .map(|h| format!("{}  {} {}", h.escape(), RESET, h.description()))
+        .collect();
+
+    writeln!(o, "{}", items.join("  "))?;
+    writeln!(o)
+}
+
+fn write_row(
+    o: &mut dyn Write,
+    columns: usize,
+    offset: usize,
+    offset_width: usize,
+    bytes: &[u8],
+    highlights: &[Option<Highlight>],
+) -> io::Result<()> {
+    let mut line = format!("{:0width$x}  ", offset, width = offset_width);
+    let mut current = None;
+
+    for (i, byte) in bytes.iter().enumerate() {
+        if i > 0 {
+            // spaces between bytes of the same color are colored too
+            let between = if highlights[i - 1] == highlights[i] {
+                highlights[i]
+            } else {
+                None
+            };
+
+            set_highlight(&mut line, &mut current, between);
+            line.push(' ');
+        }
+
+        set_highlight(&mut line, &mut current, highlights[i]);
+        utils::append_hex_byte(&mut line, *byte);
+    }
+
+    // every line is reset so that pagers can show any part of the output
+    set_highlight(&mut line, &mut current, None);
+
+    line.push_str(&"   ".repeat(columns - bytes.len()));
+    line.push_str("  ");
+
+    for &byte in bytes {
+        line.push(if byte.is_ascii_graphic() || byte == b' ' {
+            char::from(byte)
+        } else {
+            '.'
+        });
+    }
+
+    writeln!(o, "{}", line)
+}
+
+pub fn generate_hexdump(elf: &ParsedElf, options: &HexdumpOptions) -> String {
+    let mut output = vec![];
+
+    // same as with generate_report_with_options(), writing into a Vec can't fail
+    write_hexdump(&mut output, elf, options).expect("write hex dump to memory");
+
+    String::from_utf8(output).expect("hex dump is valid UTF-8")
+}
+
+pub fn write_hexdump(
+    o: &mut dyn Write,
+    elf: &ParsedElf,
+    options: &HexdumpOptions,
+) -> io::Result<()> {
+    let contents = elf.contents;
+    let columns = options.columns.get();
+    let start = options.start / columns * columns;
+    let end = match options.end {
+        Some(end) => end.div_ceil(columns).saturating_mul(columns),
+        None => contents.len(),
+    }
+    .min(contents.len());
+    let offset_width = format!("{:x}", end.max(1) - 1).len().max(8);
+
+    let mut ranges = elf
+        .ranges
+        .iter()
+        .filter_map(|(s, len, range_type)| {
+            Highlight::from_range(range_type).map(|h| (s, s.saturating_add(len), h))
+        })
+        .peekable();
+    // ends and highlights of ranges covering the current byte
+    let mut active: Vec<(usize, Highlight)> = vec![];
+    let mut highlight = None;
+    // offset where a range starts or ends next, until which the highlight stays the same
+    let mut next_change = 0;
+    let mut highlights = vec![None; columns];
+
+    if options.legend {
+        write_legend(o)?;
+    }
+
+    for row_start in (start..end).step_by(columns) {
+        let row_end = (row_start + columns).min(end);
+
+        for offset in row_start..row_end {
+            if offset >= next_change {
+                while let Some(&(range_start, range_end, range_highlight)) = ranges.peek() {
+                    if range_start > offset {
+                        break;
+                    }
+
+                    if range_end > offset {
+                        active.push((range_end, range_highlight));
+                    }
+
+                    ranges.next();
+                }
+
+                active.retain(|&(range_end, _)| range_end > offset);
+
+                highlight = classify(&active);
+
+                let next_start = ranges.peek().map_or(usize::MAX, |&(s, _, _)| s);
+                let next_end = active.iter().map(|&(e, _)| e).min().unwrap_or(usize::MAX);
+
+                next_change = next_start.min(next_end);
+            }
+
+            highlights[offset - row_start] = highlight;
+        }
+
+        write_row(
+            o,
+            columns,
+            row_start,
+            offset_width,
+            &contents[row_start..row_end],
+            &highlights[..row_end - row_start],
+        )?;
+    }
+
+    o.flush()
+}
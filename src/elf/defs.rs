@@ -23,6 +23,12 @@ pub const ELF_EV_CURRENT: u8 = 1;
 pub const ELF_OSABI_SYSV: u8 = 0;
 pub const ELF_OSABI_HPUX: u8 = 1;
 
+pub const EM_386: u16 = 3;
+pub const EM_ARM: u16 = 40;
+pub const EM_X86_64: u16 = 62;
+pub const EM_AARCH64: u16 = 183;
+pub const EM_RISCV: u16 = 243;
+
 pub const ELF_ET_NONE: u16 = 0;
 pub const ELF_ET_REL: u16 = 1;
 pub const ELF_ET_EXEC: u16 = 2;
@@ -33,6 +39,8 @@ pub const ELF_ET_HIOS: u16 = 0xfeff;
 pub const ELF_ET_LOPROC: u16 = 0xff00;
 pub const ELF_ET_HIPROC: u16 = 0xffff;
 
+pub const PN_XNUM: u16 = 0xffff;
+
 pub const PT_NULL: u32 = 0;
 pub const PT_LOAD: u32 = 1;
 pub const PT_DYNAMIC: u32 = 2;
@@ -58,6 +66,10 @@ pub const PF_MASKPROC: u32 = 0xff00_0000;
 pub const NT_GNU_BUILD_ID: u32 = 0x3;
 
 pub const SHN_UNDEF: u16 = 0;
+pub const SHN_LORESERVE: u16 = 0xff00;
+pub const SHN_ABS: u16 = 0xfff1;
+pub const SHN_COMMON: u16 = 0xfff2;
+pub const SHN_XINDEX: u16 = 0xffff;
 
 pub const SHT_NULL: u32 = 0;
 pub const SHT_PROGBITS: u32 = 1;
@@ -73,19 +85,144 @@ pub const SHT_SHLIB: u32 = 10;
 pub const SHT_DYNSYM: u32 = 11;
 pub const SHT_INIT_ARRAY: u32 = 14;
 pub const SHT_FINI_ARRAY: u32 = 15;
+pub const SHT_SYMTAB_SHNDX: u32 = 18;
 pub const SHT_LOOS: u32 = 0x6000_0000;
 pub const SHT_GNU_HASH: u32 = 0x6fff_fff6;
+pub const SHT_VER_DEF: u32 = 0x6fff_fffd;
 pub const SHT_VER_NEED: u32 = 0x6fff_fffe;
+pub const SHT_VER_SYM: u32 = 0x6fff_ffff;
 pub const SHT_HIOS: u32 = 0x6fff_ffff;
 pub const SHT_LOPROC: u32 = 0x7000_0000;
 pub const SHT_HIPROC: u32 = 0x7fff_ffff;
 
+pub const DT_NULL: i64 = 0;
+pub const DT_NEEDED: i64 = 1;
+pub const DT_PLTRELSZ: i64 = 2;
+pub const DT_PLTGOT: i64 = 3;
+pub const DT_HASH: i64 = 4;
+pub const DT_STRTAB: i64 = 5;
+pub const DT_SYMTAB: i64 = 6;
+pub const DT_RELA: i64 = 7;
+pub const DT_RELASZ: i64 = 8;
+pub const DT_RELAENT: i64 = 9;
+pub const DT_STRSZ: i64 = 10;
+pub const DT_SYMENT: i64 = 11;
+pub const DT_INIT: i64 = 12;
+pub const DT_FINI: i64 = 13;
+pub const DT_SONAME: i64 = 14;
+pub const DT_RPATH: i64 = 15;
+pub const DT_SYMBOLIC: i64 = 16;
+pub const DT_REL: i64 = 17;
+pub const DT_RELSZ: i64 = 18;
+pub const DT_RELENT: i64 = 19;
+pub const DT_PLTREL: i64 = 20;
+pub const DT_DEBUG: i64 = 21;
+pub const DT_TEXTREL: i64 = 22;
+pub const DT_JMPREL: i64 = 23;
+pub const DT_BIND_NOW: i64 = 24;
+pub const DT_INIT_ARRAY: i64 = 25;
+pub const DT_FINI_ARRAY: i64 = 26;
+pub const DT_INIT_ARRAYSZ: i64 = 27;
+pub const DT_FINI_ARRAYSZ: i64 = 28;
+pub const DT_RUNPATH: i64 = 29;
+pub const DT_FLAGS: i64 = 30;
+pub const DT_PREINIT_ARRAY: i64 = 32;
+pub const DT_PREINIT_ARRAYSZ: i64 = 33;
+pub const DT_SYMTAB_SHNDX: i64 = 34;
+pub const DT_RELRSZ: i64 = 35;
+pub const DT_RELR: i64 = 36;
+pub const DT_RELRENT: i64 = 37;
+pub const DT_GNU_PRELINKED: i64 = 0x6fff_fdf5;
+pub const DT_GNU_CONFLICTSZ: i64 = 0x6fff_fdf6;
+pub const DT_GNU_LIBLISTSZ: i64 = 0x6fff_fdf7;
+pub const DT_CHECKSUM: i64 = 0x6fff_fdf8;
+pub const DT_GNU_HASH: i64 = 0x6fff_fef5;
+pub const DT_TLSDESC_PLT: i64 = 0x6fff_fef6;
+pub const DT_TLSDESC_GOT: i64 = 0x6fff_fef7;
+pub const DT_GNU_CONFLICT: i64 = 0x6fff_fef8;
+pub const DT_GNU_LIBLIST: i64 = 0x6fff_fef9;
+pub const DT_CONFIG: i64 = 0x6fff_fefa;
+pub const DT_DEPAUDIT: i64 = 0x6fff_fefb;
+pub const DT_AUDIT: i64 = 0x6fff_fefc;
+pub const DT_VERSYM: i64 = 0x6fff_fff0;
+pub const DT_RELACOUNT: i64 = 0x6fff_fff9;
+pub const DT_RELCOUNT: i64 = 0x6fff_fffa;
+pub const DT_FLAGS_1: i64 = 0x6fff_fffb;
+pub const DT_VERDEF: i64 = 0x6fff_fffc;
+pub const DT_VERDEFNUM: i64 = 0x6fff_fffd;
+pub const DT_VERNEED: i64 = 0x6fff_fffe;
+pub const DT_VERNEEDNUM: i64 = 0x6fff_ffff;
+pub const DT_AUXILIARY: i64 = 0x7fff_fffd;
+pub const DT_FILTER: i64 = 0x7fff_ffff;
+
+pub const DF_ORIGIN: u64 = 1;
+pub const DF_SYMBOLIC: u64 = 2;
+pub const DF_TEXTREL: u64 = 4;
+pub const DF_BIND_NOW: u64 = 8;
+pub const DF_STATIC_TLS: u64 = 0x10;
+
+pub const DF_1_NOW: u64 = 0x1;
+pub const DF_1_GLOBAL: u64 = 0x2;
+pub const DF_1_GROUP: u64 = 0x4;
+pub const DF_1_NODELETE: u64 = 0x8;
+pub const DF_1_LOADFLTR: u64 = 0x10;
+pub const DF_1_INITFIRST: u64 = 0x20;
+pub const DF_1_NOOPEN: u64 = 0x40;
+pub const DF_1_ORIGIN: u64 = 0x80;
+pub const DF_1_DIRECT: u64 = 0x100;
+pub const DF_1_INTERPOSE: u64 = 0x400;
+pub const DF_1_NODEFLIB: u64 = 0x800;
+pub const DF_1_NODUMP: u64 = 0x1000;
+pub const DF_1_CONFALT: u64 = 0x2000;
+pub const DF_1_ENDFILTEE: u64 = 0x4000;
+pub const DF_1_DISPRELDNE: u64 = 0x8000;
+pub const DF_1_DISPRELPND: u64 = 0x1_0000;
+pub const DF_1_NODIRECT: u64 = 0x2_0000;
+pub const DF_1_IGNMULDEF: u64 = 0x4_0000;
+pub const DF_1_NOKSYMS: u64 = 0x8_0000;
+pub const DF_1_NOHDR: u64 = 0x10_0000;
+pub const DF_1_EDITED: u64 = 0x20_0000;
+pub const DF_1_NORELOC: u64 = 0x40_0000;
+pub const DF_1_SYMINTPOSE: u64 = 0x80_0000;
+pub const DF_1_GLOBAUDIT: u64 = 0x100_0000;
+pub const DF_1_SINGLETON: u64 = 0x200_0000;
+pub const DF_1_STUB: u64 = 0x400_0000;
+pub const DF_1_PIE: u64 = 0x800_0000;
+
 pub const SHF_WRITE: u64 = 0b001;
 pub const SHF_ALLOC: u64 = 0b010;
 pub const SHF_EXECINSTR: u64 = 0b100;
+pub const SHF_TLS: u64 = 0x400;
 pub const SHF_MASKOS: u64 = 0x0f00_0000;
 pub const SHF_MASKPROC: u64 = 0xf000_0000;
 
+pub const VER_NEED_CURRENT: u16 = 1;
+pub const VER_DEF_CURRENT: u16 = 1;
+
+pub const VER_FLG_BASE: u16 = 0x1;
+pub const VER_FLG_WEAK: u16 = 0x2;
+
+pub const VERSYM_HIDDEN: u16 = 0x8000;
+
+pub const STB_LOCAL: u8 = 0;
+pub const STB_GLOBAL: u8 = 1;
+pub const STB_WEAK: u8 = 2;
+pub const STB_GNU_UNIQUE: u8 = 10;
+
+pub const STT_NOTYPE: u8 = 0;
+pub const STT_OBJECT: u8 = 1;
+pub const STT_FUNC: u8 = 2;
+pub const STT_SECTION: u8 = 3;
+pub const STT_FILE: u8 = 4;
+pub const STT_COMMON: u8 = 5;
+pub const STT_TLS: u8 = 6;
+pub const STT_GNU_IFUNC: u8 = 10;
+
+pub const STV_DEFAULT: u8 = 0;
+pub const STV_INTERNAL: u8 = 1;
+pub const STV_HIDDEN: u8 = 2;
+pub const STV_PROTECTED: u8 = 3;
+
 pub fn type_to_string(e_type: u16) -> String {
     match e_type {
         ELF_ET_NONE => String::from("None (NONE)"),
@@ -124,7 +261,7 @@ pub fn machine_to_string(e_machine: u16) -> String {
         0 => String::from("None"),
         1 => String::from("AT&T WE 32100"),
         2 => String::from("SPARC"),
-        3 => String::from("x86"),
+        EM_386 => String::from("x86"),
         4 => String::from("Motorolla 68000"),
         5 => String::from("Motorolla 88000"),
         6 => String::from("Intel MCU"),
@@ -137,13 +274,13 @@ pub fn machine_to_string(e_machine: u16) -> String {
         20 => String::from("PowerPC"),
         21 => String::from("PowerPC 64-bit"),
         22 => String::from("S390"),
-        40 => String::from("ARM Aarch32"),
+        EM_ARM => String::from("ARM Aarch32"),
         50 => String::from("Itanium IA-64"),
-        62 => String::from("x86-64"),
-        183 => String::from("ARM Aarch64"),
+        EM_X86_64 => String::from("x86-64"),
+        EM_AARCH64 => String::from("ARM Aarch64"),
         190 => String::from("CUDA"),
         224 => String::from("AMDGPU"),
-        243 => String::from("RISC-V"),
+        EM_RISCV => String::from("RISC-V"),
         x => format!("Unknown: {}", x),
     }
 }
@@ -203,10 +340,12 @@ pub fn shtype_to_string(shtype: u32) -> String {
         SHT_INIT_ARRAY => String::from("INIT_ARRAY"),
         SHT_FINI_ARRAY => String::from("FINI_ARRAY"),
         SHT_DYNSYM => String::from("DYNSYM"),
+        SHT_SYMTAB_SHNDX => String::from("SYMTAB_SHNDX"),
         SHT_LOOS => String::from("LOOS"),
         SHT_GNU_HASH => String::from("GNU_HASH (OS-specific)"),
+        SHT_VER_DEF => String::from("VER_DEF (OS-specific)"),
         SHT_VER_NEED => String::from("VER_NEED (OS-specific)"),
-        SHT_HIOS => String::from("HIOS"),
+        SHT_VER_SYM => String::from("VER_SYM (OS-specific)"),
         SHT_LOPROC => String::from("LOPROC"),
         SHT_HIPROC => String::from("HIPROC"),
         x => format!("Unknown: {}", x),
@@ -234,3 +373,512 @@ pub fn shflags_to_string(flags: u64) -> String {
 
     s
 }
+
+pub fn symbind_to_string(bind: u8) -> String {
+    match bind {
+        STB_LOCAL => String::from("LOCAL"),
+        STB_GLOBAL => String::from("GLOBAL"),
+        STB_WEAK => String::from("WEAK"),
+        STB_GNU_UNIQUE => String::from("GNU_UNIQUE"),
+        x => format!("Unknown: {}", x),
+    }
+}
+
+pub fn symtype_to_string(stype: u8) -> String {
+    match stype {
+        STT_NOTYPE => String::from("NOTYPE"),
+        STT_OBJECT => String::from("OBJECT"),
+        STT_FUNC => String::from("FUNC"),
+        STT_SECTION => String::from("SECTION"),
+        STT_FILE => String::from("FILE"),
+        STT_COMMON => String::from("COMMON"),
+        STT_TLS => String::from("TLS"),
+        STT_GNU_IFUNC => String::from("GNU_IFUNC"),
+        x => format!("Unknown: {}", x),
+    }
+}
+
+pub fn symvis_to_string(visibility: u8) -> String {
+    match visibility {
+        STV_DEFAULT => String::from("DEFAULT"),
+        STV_INTERNAL => String::from("INTERNAL"),
+        STV_HIDDEN => String::from("HIDDEN"),
+        STV_PROTECTED => String::from("PROTECTED"),
+        x => format!("Unknown: {}", x),
+    }
+}
+
+pub fn shndx_to_string(shndx: u16) -> String {
+    match shndx {
+        SHN_UNDEF => String::from("UND"),
+        SHN_ABS => String::from("ABS"),
+        SHN_COMMON => String::from("COMMON"),
+        SHN_XINDEX => String::from("XINDEX"),
+        x => format!("{}", x),
+    }
+}
+
+pub fn dtag_to_string(tag: i64) -> String {
+    match tag {
+        DT_NULL => String::from("NULL"),
+        DT_NEEDED => String::from("NEEDED"),
+        DT_PLTRELSZ => String::from("PLTRELSZ"),
+        DT_PLTGOT => String::from("PLTGOT"),
+        DT_HASH => String::from("HASH"),
+        DT_STRTAB => String::from("STRTAB"),
+        DT_SYMTAB => String::from("SYMTAB"),
+        DT_RELA => String::from("RELA"),
+        DT_RELASZ => String::from("RELASZ"),
+        DT_RELAENT => String::from("RELAENT"),
+        DT_STRSZ => String::from("STRSZ"),
+        DT_SYMENT => String::from("SYMENT"),
+        DT_INIT => String::from("INIT"),
+        DT_FINI => String::from("FINI"),
+        DT_SONAME => String::from("SONAME"),
+        DT_RPATH => String::from("RPATH"),
+        DT_SYMBOLIC => String::from("SYMBOLIC"),
+        DT_REL => String::from("REL"),
+        DT_RELSZ => String::from("RELSZ"),
+        DT_RELENT => String::from("RELENT"),
+        DT_PLTREL => String::from("PLTREL"),
+        DT_DEBUG => String::from("DEBUG"),
+        DT_TEXTREL => String::from("TEXTREL"),
+        DT_JMPREL => String::from("JMPREL"),
+        DT_BIND_NOW => String::from("BIND_NOW"),
+        DT_INIT_ARRAY => String::from("INIT_ARRAY"),
+        DT_FINI_ARRAY => String::from("FINI_ARRAY"),
+        DT_INIT_ARRAYSZ => String::from("INIT_ARRAYSZ"),
+        DT_FINI_ARRAYSZ => String::from("FINI_ARRAYSZ"),
+        DT_RUNPATH => String::from("RUNPATH"),
+        DT_FLAGS => String::from("FLAGS"),
+        DT_PREINIT_ARRAY => String::from("PREINIT_ARRAY"),
+        DT_PREINIT_ARRAYSZ => String::from("PREINIT_ARRAYSZ"),
+        DT_SYMTAB_SHNDX => String::from("SYMTAB_SHNDX"),
+        DT_RELRSZ => String::from("RELRSZ"),
+        DT_RELR => String::from("RELR"),
+        DT_RELRENT => String::from("RELRENT"),
+        DT_GNU_PRELINKED => String::from("GNU_PRELINKED"),
+        DT_GNU_CONFLICTSZ => String::from("GNU_CONFLICTSZ"),
+        DT_GNU_LIBLISTSZ => String::from("GNU_LIBLISTSZ"),
+        DT_CHECKSUM => String::from("CHECKSUM"),
+        DT_GNU_HASH => String::from("GNU_HASH"),
+        DT_TLSDESC_PLT => String::from("TLSDESC_PLT"),
+        DT_TLSDESC_GOT => String::from("TLSDESC_GOT"),
+        DT_GNU_CONFLICT => String::from("GNU_CONFLICT"),
+        DT_GNU_LIBLIST => String::from("GNU_LIBLIST"),
+        DT_CONFIG => String::from("CONFIG"),
+        DT_DEPAUDIT => String::from("DEPAUDIT"),
+        DT_AUDIT => String::from("AUDIT"),
+        DT_VERSYM => String::from("VERSYM"),
+        DT_RELACOUNT => String::from("RELACOUNT"),
+        DT_RELCOUNT => String::from("RELCOUNT"),
+        DT_FLAGS_1 => String::from("FLAGS_1"),
+        DT_VERDEF => String::from("VERDEF"),
+        DT_VERDEFNUM => String::from("VERDEFNUM"),
+        DT_VERNEED => String::from("VERNEED"),
+        DT_VERNEEDNUM => String::from("VERNEEDNUM"),
+        DT_AUXILIARY => String::from("AUXILIARY"),
+        DT_FILTER => String::from("FILTER"),
+        x => format!("Unknown: {:#x}", x),
+    }
+}
+
+// tags whose value is an offset into the string table
+pub fn dtag_is_string(tag: i64) -> bool {
+    matches!(
+        tag,
+        DT_NEEDED
+            | DT_SONAME
+            | DT_RPATH
+            | DT_RUNPATH
+            | DT_CONFIG
+            | DT_DEPAUDIT
+            | DT_AUDIT
+            | DT_AUXILIARY
+            | DT_FILTER
+    )
+}
+
+// tags whose value is a virtual address
+pub fn dtag_is_address(tag: i64) -> bool {
+    matches!(
+        tag,
+        DT_PLTGOT
+            | DT_HASH
+            | DT_STRTAB
+            | DT_SYMTAB
+            | DT_RELA
+            | DT_INIT
+            | DT_FINI
+            | DT_REL
+            | DT_JMPREL
+            | DT_INIT_ARRAY
+            | DT_FINI_ARRAY
+            | DT_PREINIT_ARRAY
+            | DT_SYMTAB_SHNDX
+            | DT_RELR
+            | DT_GNU_HASH
+            | DT_VERSYM
+            | DT_VERDEF
+            | DT_VERNEED
+    )
+}
+
+fn flags_to_string(flags: u64, names: &[(u64, &str)]) -> String {
+    let mut known = 0;
+    let mut parts = vec![];
+
+    for (flag, name) in names {
+        if flags & flag != 0 {
+            parts.push(name.to_string());
+            known |= flag;
+        }
+    }
+
+    if flags & !known != 0 {
+        parts.push(format!("{:#x}", flags & !known));
+    }
+
+    parts.join(" ")
+}
+
+pub fn verflags_to_string(flags: u16) -> String {
+    let names = [(VER_FLG_BASE as u64, "BASE"), (VER_FLG_WEAK as u64, "WEAK")];
+
+    if flags == 0 {
+        String::from("none")
+    } else {
+        flags_to_string(flags.into(), &names)
+    }
+}
+
+pub fn dflags_to_string(flags: u64) -> String {
+    let names = [
+        (DF_ORIGIN, "ORIGIN"),
+        (DF_SYMBOLIC, "SYMBOLIC"),
+        (DF_TEXTREL, "TEXTREL"),
+        (DF_BIND_NOW, "BIND_NOW"),
+        (DF_STATIC_TLS, "STATIC_TLS"),
+    ];
+
+    flags_to_string(flags, &names)
+}
+
+pub fn dflags1_to_string(flags: u64) -> String {
+    let names = [
+        (DF_1_NOW, "NOW"),
+        (DF_1_GLOBAL, "GLOBAL"),
+        (DF_1_GROUP, "GROUP"),
+        (DF_1_NODELETE, "NODELETE"),
+        (DF_1_LOADFLTR, "LOADFLTR"),
+        (DF_1_INITFIRST, "INITFIRST"),
+        (DF_1_NOOPEN, "NOOPEN"),
+        (DF_1_ORIGIN, "ORIGIN"),
+        (DF_1_DIRECT, "DIRECT"),
+        (DF_1_INTERPOSE, "INTERPOSE"),
+        (DF_1_NODEFLIB, "NODEFLIB"),
+        (DF_1_NODUMP, "NODUMP"),
+        (DF_1_CONFALT, "CONFALT"),
+        (DF_1_ENDFILTEE, "ENDFILTEE"),
+        (DF_1_DISPRELDNE, "DISPRELDNE"),
+        (DF_1_DISPRELPND, "DISPRELPND"),
+        (DF_1_NODIRECT, "NODIRECT"),
+        (DF_1_IGNMULDEF, "IGNMULDEF"),
+        (DF_1_NOKSYMS, "NOKSYMS"),
+        (DF_1_NOHDR, "NOHDR"),
+        (DF_1_EDITED, "EDITED"),
+        (DF_1_NORELOC, "NORELOC"),
+        (DF_1_SYMINTPOSE, "SYMINTPOSE"),
+        (DF_1_GLOBAUDIT, "GLOBAUDIT"),
+        (DF_1_SINGLETON, "SINGLETON"),
+        (DF_1_STUB, "STUB"),
+        (DF_1_PIE, "PIE"),
+    ];
+
+    flags_to_string(flags, &names)
+}
+
+pub fn reltype_to_string(machine: u16, rtype: u32) -> String {
+    let (prefix, name) = match machine {
+        EM_386 => ("R_386_", reltype_i386_to_str(rtype)),
+        EM_ARM => ("R_ARM_", reltype_arm_to_str(rtype)),
+        EM_X86_64 => ("R_X86_64_", reltype_x86_64_to_str(rtype)),
+        EM_AARCH64 => ("R_AARCH64_", reltype_aarch64_to_str(rtype)),
+        EM_RISCV => ("R_RISCV_", reltype_riscv_to_str(rtype)),
+        _ => ("", None),
+    };
+
+    match name {
+        Some(name) => format!("{}{}", prefix, name),
+        None => format!("Unknown: {}", rtype),
+    }
+}
+
+fn reltype_x86_64_to_str(rtype: u32) -> Option<&'static str> {
+    Some(match rtype {
+        0 => "NONE",
+        1 => "64",
+        2 => "PC32",
+        3 => "GOT32",
+        4 => "PLT32",
+        5 => "COPY",
+        6 => "GLOB_DAT",
+        7 => "JUMP_SLOT",
+        8 => "RELATIVE",
+        9 => "GOTPCREL",
+        10 => "32",
+        11 => "32S",
+        12 => "16",
+        13 => "PC16",
+        14 => "8",
+        15 => "PC8",
+        16 => "DTPMOD64",
+        17 => "DTPOFF64",
+        18 => "TPOFF64",
+        19 => "TLSGD",
+        20 => "TLSLD",
+        21 => "DTPOFF32",
+        22 => "GOTTPOFF",
+        23 => "TPOFF32",
+        24 => "PC64",
+        25 => "GOTOFF64",
+        26 => "GOTPC32",
+        27 => "GOT64",
+        28 => "GOTPCREL64",
+        29 => "GOTPC64",
+        30 => "GOTPLT64",
+        31 => "PLTOFF64",
+        32 => "SIZE32",
+        33 => "SIZE64",
+        34 => "GOTPC32_TLSDESC",
+        35 => "TLSDESC_CALL",
+        36 => "TLSDESC",
+        37 => "IRELATIVE",
+        38 => "RELATIVE64",
+        41 => "GOTPCRELX",
+        42 => "REX_GOTPCRELX",
+        _ => return None,
+    })
+}
+
+fn reltype_i386_to_str(rtype: u32) -> Option<&'static str> {
+    Some(match rtype {
+        0 => "NONE",
+        1 => "32",
+        2 => "PC32",
+        3 => "GOT32",
+        4 => "PLT32",
+        5 => "COPY",
+        6 => "GLOB_DAT",
+        7 => "JMP_SLOT",
+        8 => "RELATIVE",
+        9 => "GOTOFF",
+        10 => "GOTPC",
+        11 => "32PLT",
+        14 => "TLS_TPOFF",
+        15 => "TLS_IE",
+        16 => "TLS_GOTIE",
+        17 => "TLS_LE",
+        18 => "TLS_GD",
+        19 => "TLS_LDM",
+        20 => "16",
+        21 => "PC16",
+        22 => "8",
+        23 => "PC8",
+        24 => "TLS_GD_32",
+        25 => "TLS_GD_PUSH",
+        26 => "TLS_GD_CALL",
+        27 => "TLS_GD_POP",
+        28 => "TLS_LDM_32",
+        29 => "TLS_LDM_PUSH",
+        30 => "TLS_LDM_CALL",
+        31 => "TLS_LDM_POP",
+        32 => "TLS_LDO_32",
+        33 => "TLS_IE_32",
+        34 => "TLS_LE_32",
+        35 => "TLS_DTPMOD32",
+        36 => "TLS_DTPOFF32",
+        37 => "TLS_TPOFF32",
+        38 => "SIZE32",
+        39 => "TLS_GOTDESC",
+        40 => "TLS_DESC_CALL",
+        41 => "TLS_DESC",
+        42 => "IRELATIVE",
+        43 => "GOT32X",
+        _ => return None,
+    })
+}
+
+fn reltype_aarch64_to_str(rtype: u32) -> Option<&'static str> {
+    Some(match rtype {
+        0 => "NONE",
+        257 => "ABS64",
+        258 => "ABS32",
+        259 => "ABS16",
+        260 => "PREL64",
+        261 => "PREL32",
+        262 => "PREL16",
+        263 => "MOVW_UABS_G0",
+        264 => "MOVW_UABS_G0_NC",
+        265 => "MOVW_UABS_G1",
+        266 => "MOVW_UABS_G1_NC",
+        267 => "MOVW_UABS_G2",
+        268 => "MOVW_UABS_G2_NC",
+        269 => "MOVW_UABS_G3",
+        270 => "MOVW_SABS_G0",
+        271 => "MOVW_SABS_G1",
+        272 => "MOVW_SABS_G2",
+        273 => "LD_PREL_LO19",
+        274 => "ADR_PREL_LO21",
+        275 => "ADR_PREL_PG_HI21",
+        276 => "ADR_PREL_PG_HI21_NC",
+        277 => "ADD_ABS_LO12_NC",
+        278 => "LDST8_ABS_LO12_NC",
+        279 => "TSTBR14",
+        280 => "CONDBR19",
+        282 => "JUMP26",
+        283 => "CALL26",
+        284 => "LDST16_ABS_LO12_NC",
+        285 => "LDST32_ABS_LO12_NC",
+        286 => "LDST64_ABS_LO12_NC",
+        299 => "LDST128_ABS_LO12_NC",
+        309 => "GOT_LD_PREL19",
+        311 => "ADR_GOT_PAGE",
+        312 => "LD64_GOT_LO12_NC",
+        512 => "TLSGD_ADR_PAGE21",
+        513 => "TLSGD_ADD_LO12_NC",
+        541 => "TLSIE_ADR_GOTTPREL_PAGE21",
+        542 => "TLSIE_LD64_GOTTPREL_LO12_NC",
+        549 => "TLSLE_ADD_TPREL_HI12",
+        550 => "TLSLE_ADD_TPREL_LO12",
+        551 => "TLSLE_ADD_TPREL_LO12_NC",
+        560 => "TLSDESC_LD_PREL19",
+        561 => "TLSDESC_ADR_PREL21",
+        562 => "TLSDESC_ADR_PAGE21",
+        563 => "TLSDESC_LD64_LO12",
+        564 => "TLSDESC_ADD_LO12",
+        569 => "TLSDESC_CALL",
+        1024 => "COPY",
+        1025 => "GLOB_DAT",
+        1026 => "JUMP_SLOT",
+        1027 => "RELATIVE",
+        1028 => "TLS_DTPMOD",
+        1029 => "TLS_DTPREL",
+        1030 => "TLS_TPREL",
+        1031 => "TLSDESC",
+        1032 => "IRELATIVE",
+        _ => return None,
+    })
+}
+
+fn reltype_arm_to_str(rtype: u32) -> Option<&'static str> {
+    Some(match rtype {
+        0 => "NONE",
+        1 => "PC24",
+        2 => "ABS32",
+        3 => "REL32",
+        4 => "LDR_PC_G0",
+        5 => "ABS16",
+        6 => "ABS12",
+        7 => "THM_ABS5",
+        8 => "ABS8",
+        9 => "SBREL32",
+        10 => "THM_CALL",
+        11 => "THM_PC8",
+        12 => "BREL_ADJ",
+        13 => "TLS_DESC",
+        17 => "TLS_DTPMOD32",
+        18 => "TLS_DTPOFF32",
+        19 => "TLS_TPOFF32",
+        20 => "COPY",
+        21 => "GLOB_DAT",
+        22 => "JUMP_SLOT",
+        23 => "RELATIVE",
+        24 => "GOTOFF32",
+        25 => "BASE_PREL",
+        26 => "GOT_BREL",
+        27 => "PLT32",
+        28 => "CALL",
+        29 => "JUMP24",
+        30 => "THM_JUMP24",
+        31 => "BASE_ABS",
+        38 => "TARGET1",
+        40 => "V4BX",
+        41 => "TARGET2",
+        42 => "PREL31",
+        43 => "MOVW_ABS_NC",
+        44 => "MOVT_ABS",
+        45 => "MOVW_PREL_NC",
+        46 => "MOVT_PREL",
+        47 => "THM_MOVW_ABS_NC",
+        48 => "THM_MOVT_ABS",
+        49 => "THM_MOVW_PREL_NC",
+        50 => "THM_MOVT_PREL",
+        51 => "THM_JUMP19",
+        102 => "THM_JUMP11",
+        103 => "THM_JUMP8",
+        104 => "TLS_GD32",
+        105 => "TLS_LDM32",
+        106 => "TLS_LDO32",
+        107 => "TLS_IE32",
+        108 => "TLS_LE32",
+        160 => "IRELATIVE",
+        _ => return None,
+    })
+}
+
+fn reltype_riscv_to_str(rtype: u32) -> Option<&'static str> {
+    Some(match rtype {
+        0 => "NONE",
+        1 => "32",
+        2 => "64",
+        3 => "RELATIVE",
+        4 => "COPY",
+        5 => "JUMP_SLOT",
+        6 => "TLS_DTPMOD32",
+        7 => "TLS_DTPMOD64",
+        8 => "TLS_DTPREL32",
+        9 => "TLS_DTPREL64",
+        10 => "TLS_TPREL32",
+        11 => "TLS_TPREL64",
+        12 => "TLSDESC",
+        16 => "BRANCH",
+        17 => "JAL",
+        18 => "CALL",
+        19 => "CALL_PLT",
+        20 => "GOT_HI20",
+        21 => "TLS_GOT_HI20",
+        22 => "TLS_GD_HI20",
+        23 => "PCREL_HI20",
+        24 => "PCREL_LO12_I",
+        25 => "PCREL_LO12_S",
+        26 => "HI20",
+        27 => "LO12_I",
+        28 => "LO12_S",
+        29 => "TPREL_HI20",
+        30 => "TPREL_LO12_I",
+        31 => "TPREL_LO12_S",
+        32 => "TPREL_ADD",
+        33 => "ADD8",
+        34 => "ADD16",
+        35 => "ADD32",
+        36 => "ADD64",
+        37 => "SUB8",
+        38 => "SUB16",
+        39 => "SUB32",
+        40 => "SUB64",
+        43 => "ALIGN",
+        44 => "RVC_BRANCH",
+        45 => "RVC_JUMP",
+        51 => "RELAX",
+        52 => "SUB6",
+        53 => "SET6",
+        54 => "SET8",
+        55 => "SET16",
+        56 => "SET32",
+        57 => "32_PCREL",
+        58 => "IRELATIVE",
+        59 => "PLT32",
+        _ => return None,
+    })
+}
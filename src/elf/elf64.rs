@@ -7,6 +7,7 @@ type Elf64Off = u64;
 type Elf64Half = u16;
 type Elf64Word = u32;
 type Elf64Xword = u64;
+type Elf64Sxword = i64;
 
 pub struct Elf64Ehdr {
     e_ident: [u8; 16],
@@ -49,6 +50,31 @@ pub struct Elf64Shdr {
     sh_entsize: Elf64Xword,
 }
 
+pub struct Elf64Sym {
+    st_name: Elf64Word,
+    st_info: u8,
+    st_other: u8,
+    st_shndx: Elf64Half,
+    st_value: Elf64Addr,
+    st_size: Elf64Xword,
+}
+
+pub struct Elf64Rel {
+    r_offset: Elf64Addr,
+    r_info: Elf64Xword,
+}
+
+pub struct Elf64Rela {
+    r_offset: Elf64Addr,
+    r_info: Elf64Xword,
+    r_addend: Elf64Sxword,
+}
+
+pub struct Elf64Dyn {
+    d_tag: Elf64Sxword,
+    d_val: Elf64Xword,
+}
+
 pub struct Elf64;
 
 // All this just to avoid unsafe. This should be improved.
@@ -161,6 +187,92 @@ impl ElfHeader for Elf64Shdr {
     }
 }
 
+#[rustfmt::skip]
+impl ElfHeader for Elf64Sym {
+    fn describe() -> String {
+        String::from("symbol table entry")
+    }
+    fn from_le_bytes(buf: &[u8]) -> Result<Elf64Sym, ReadErr> {
+        Ok(Elf64Sym {
+            st_name:  Elf64Word:: from_le_bytes(buf[ 0.. 4].try_into()?),
+            st_info:  buf[4],
+            st_other: buf[5],
+            st_shndx: Elf64Half:: from_le_bytes(buf[ 6.. 8].try_into()?),
+            st_value: Elf64Addr:: from_le_bytes(buf[ 8..16].try_into()?),
+            st_size:  Elf64Xword::from_le_bytes(buf[16..24].try_into()?),
+        })
+    }
+    fn from_be_bytes(buf: &[u8]) -> Result<Elf64Sym, ReadErr> {
+        Ok(Elf64Sym {
+            st_name:  Elf64Word:: from_be_bytes(buf[ 0.. 4].try_into()?),
+            st_info:  buf[4],
+            st_other: buf[5],
+            st_shndx: Elf64Half:: from_be_bytes(buf[ 6.. 8].try_into()?),
+            st_value: Elf64Addr:: from_be_bytes(buf[ 8..16].try_into()?),
+            st_size:  Elf64Xword::from_be_bytes(buf[16..24].try_into()?),
+        })
+    }
+}
+
+#[rustfmt::skip]
+impl ElfHeader for Elf64Rel {
+    fn describe() -> String {
+        String::from("relocation entry")
+    }
+    fn from_le_bytes(buf: &[u8]) -> Result<Elf64Rel, ReadErr> {
+        Ok(Elf64Rel {
+            r_offset: Elf64Addr:: from_le_bytes(buf[ 0.. 8].try_into()?),
+            r_info:   Elf64Xword::from_le_bytes(buf[ 8..16].try_into()?),
+        })
+    }
+    fn from_be_bytes(buf: &[u8]) -> Result<Elf64Rel, ReadErr> {
+        Ok(Elf64Rel {
+            r_offset: Elf64Addr:: from_be_bytes(buf[ 0.. 8].try_into()?),
+            r_info:   Elf64Xword::from_be_bytes(buf[ 8..16].try_into()?),
+        })
+    }
+}
+
+#[rustfmt::skip]
+impl ElfHeader for Elf64Rela {
+    fn describe() -> String {
+        String::from("relocation entry with addend")
+    }
+    fn from_le_bytes(buf: &[u8]) -> Result<Elf64Rela, ReadErr> {
+        Ok(Elf64Rela {
+            r_offset: Elf64Addr:: from_le_bytes(buf[ 0.. 8].try_into()?),
+            r_info:   Elf64Xword::from_le_bytes(buf[ 8..16].try_into()?),
+            r_addend: Elf64Sxword::from_le_bytes(buf[16..24].try_into()?),
+        })
+    }
+    fn from_be_bytes(buf: &[u8]) -> Result<Elf64Rela, ReadErr> {
+        Ok(Elf64Rela {
+            r_offset: Elf64Addr:: from_be_bytes(buf[ 0.. 8].try_into()?),
+            r_info:   Elf64Xword::from_be_bytes(buf[ 8..16].try_into()?),
+            r_addend: Elf64Sxword::from_be_bytes(buf[16..24].try_into()?),
+        })
+    }
+}
+
+#[rustfmt::skip]
+impl ElfHeader for Elf64Dyn {
+    fn describe() -> String {
+        String::from("dynamic section entry")
+    }
+    fn from_le_bytes(buf: &[u8]) -> Result<Elf64Dyn, ReadErr> {
+        Ok(Elf64Dyn {
+            d_tag: Elf64Sxword::from_le_bytes(buf[ 0.. 8].try_into()?),
+            d_val: Elf64Xword:: from_le_bytes(buf[ 8..16].try_into()?),
+        })
+    }
+    fn from_be_bytes(buf: &[u8]) -> Result<Elf64Dyn, ReadErr> {
+        Ok(Elf64Dyn {
+            d_tag: Elf64Sxword::from_be_bytes(buf[ 0.. 8].try_into()?),
+            d_val: Elf64Xword:: from_be_bytes(buf[ 8..16].try_into()?),
+        })
+    }
+}
+
 #[rustfmt::skip]
 impl ElfXXEhdr<Elf64Addr, Elf64Half, Elf64Word, Elf64Off> for Elf64Ehdr {
     fn e_ident(&self)     -> [u8; 16]  { self.e_ident     }
@@ -206,8 +318,40 @@ impl ElfXXShdr<Elf64Addr, Elf64Word, Elf64Off, Elf64Xword> for Elf64Shdr {
 }
 
 #[rustfmt::skip]
-impl ElfXX<Elf64Ehdr, Elf64Phdr, Elf64Shdr, Elf64Addr, Elf64Half, Elf64Word, Elf64Off, Elf64Xword>
-    for Elf64
+impl ElfXXSym<Elf64Addr, Elf64Half, Elf64Word, Elf64Xword> for Elf64Sym {
+    fn st_name(&self)  -> Elf64Word  { self.st_name  }
+    fn st_value(&self) -> Elf64Addr  { self.st_value }
+    fn st_size(&self)  -> Elf64Xword { self.st_size  }
+    fn st_info(&self)  -> u8         { self.st_info  }
+    fn st_other(&self) -> u8         { self.st_other }
+    fn st_shndx(&self) -> Elf64Half  { self.st_shndx }
+}
+
+#[rustfmt::skip]
+impl ElfXXRel<Elf64Addr, Elf64Xword> for Elf64Rel {
+    fn r_offset(&self) -> Elf64Addr   { self.r_offset }
+    fn r_info(&self)   -> Elf64Xword  { self.r_info   }
+    fn r_addend(&self) -> Option<i64> { None }
+}
+
+#[rustfmt::skip]
+impl ElfXXRel<Elf64Addr, Elf64Xword> for Elf64Rela {
+    fn r_offset(&self) -> Elf64Addr   { self.r_offset }
+    fn r_info(&self)   -> Elf64Xword  { self.r_info   }
+    fn r_addend(&self) -> Option<i64> { Some(self.r_addend) }
+}
+
+#[rustfmt::skip]
+impl ElfXXDyn<Elf64Xword> for Elf64Dyn {
+    fn d_tag(&self) -> i64        { self.d_tag        }
+    fn d_val(&self) -> Elf64Xword { self.d_val        }
+}
+
+#[rustfmt::skip]
+impl ElfXX<
+    Elf64Ehdr, Elf64Phdr, Elf64Shdr, Elf64Sym, Elf64Rel, Elf64Rela, Elf64Dyn,
+    Elf64Addr, Elf64Half, Elf64Word, Elf64Off, Elf64Xword,
+> for Elf64
 {
     fn add_ehdr_ranges(ehdr: &Elf64Ehdr, ranges: &mut Ranges) {
         ranges.add_range(0,  ehdr.e_ehsize as usize, RangeType::FileHeader);
@@ -249,4 +393,35 @@ impl ElfXX<Elf64Ehdr, Elf64Phdr, Elf64Shdr, Elf64Addr, Elf64Half, Elf64Word, Elf
         ranges.add_range(start + 48, 8, RangeType::ShdrField("sh_addralign"));
         ranges.add_range(start + 56, 8, RangeType::ShdrField("sh_entsize"));
     }
+
+    fn add_sym_ranges(start: usize, ranges: &mut Ranges) {
+        ranges.add_range(start,      4, RangeType::SymbolField("st_name"));
+        ranges.add_range(start +  4, 1, RangeType::SymbolField("st_info"));
+        ranges.add_range(start +  5, 1, RangeType::SymbolField("st_other"));
+        ranges.add_range(start +  6, 2, RangeType::SymbolField("st_shndx"));
+        ranges.add_range(start +  8, 8, RangeType::SymbolField("st_value"));
+        ranges.add_range(start + 16, 8, RangeType::SymbolField("st_size"));
+    }
+
+    fn add_rel_ranges(start: usize, has_addend: bool, ranges: &mut Ranges) {
+        ranges.add_range(start,      8, RangeType::RelocationField("r_offset"));
+        ranges.add_range(start +  8, 8, RangeType::RelocationField("r_info"));
+
+        if has_addend {
+            ranges.add_range(start + 16, 8, RangeType::RelocationField("r_addend"));
+        }
+    }
+
+    fn add_dyn_ranges(start: usize, ranges: &mut Ranges) {
+        ranges.add_range(start,      8, RangeType::DynamicField("d_tag"));
+        ranges.add_range(start +  8, 8, RangeType::DynamicField("d_val"));
+    }
+
+    fn r_sym(info: u64) -> u32 {
+        (info >> 32) as u32
+    }
+
+    fn r_type(info: u64) -> u32 {
+        (info & 0xffff_ffff) as u32
+    }
 }
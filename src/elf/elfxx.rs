@@ -1,4 +1,5 @@
 use super::defs::*;
+use super::error::{ElfError, ErrorKind};
 use super::parser::*;
 use std::mem::size_of;
 
@@ -7,26 +8,37 @@ pub trait ElfHeader: Sized {
     fn from_be_bytes(buf: &[u8]) -> Result<Self, ReadErr>;
     fn describe() -> String;
 
-    fn from_bytes(buf: &[u8], endianness: u8) -> Result<Self, String> {
+    fn from_bytes(buf: &[u8], endianness: u8) -> Result<Self, ElfError> {
         if endianness == ELF_DATA2LSB {
             Self::from_le_bytes(buf)
         } else {
             Self::from_be_bytes(buf)
         }
-        .map_err(|a| format!("failed to read {}: {}", Self::describe(), a))
+        .map_err(|_| ElfError::new(ErrorKind::Truncated, Self::describe(), None))
+    }
+
+    // Reads the structure at `offset` in file, checking that it fits
+    fn read(buf: &[u8], offset: usize, endianness: u8) -> Result<Self, ElfError> {
+        let truncated = || ElfError::new(ErrorKind::Truncated, Self::describe(), Some(offset));
+        let bytes = file_slice(buf, offset, size_of::<Self>()).ok_or_else(truncated)?;
+
+        Self::from_bytes(bytes, endianness).map_err(|_| truncated())
     }
 }
 
 // We do this because we can't access struct fields of a generic type
 pub trait ElfXXEhdr<ElfXXAddr, ElfXXHalf, ElfXXWord, ElfXXOff>: ElfHeader {
+    #[allow(dead_code)]
     fn e_ident(&self) -> [u8; 16];
     fn e_type(&self) -> ElfXXHalf;
     fn e_machine(&self) -> ElfXXHalf;
+    #[allow(dead_code)]
     fn e_version(&self) -> ElfXXWord;
     fn e_entry(&self) -> ElfXXAddr;
     fn e_phoff(&self) -> ElfXXOff;
     fn e_shoff(&self) -> ElfXXOff;
     fn e_flags(&self) -> ElfXXWord;
+    #[allow(dead_code)]
     fn e_ehsize(&self) -> ElfXXHalf;
     fn e_phentsize(&self) -> ElfXXHalf;
     fn e_phnum(&self) -> ElfXXHalf;
@@ -40,6 +52,7 @@ pub trait ElfXXPhdr<ElfXXAddr, ElfXXWord, ElfXXOff, ElfXXXword>: ElfHeader {
     fn p_flags(&self) -> ElfXXWord;
     fn p_offset(&self) -> ElfXXOff;
     fn p_vaddr(&self) -> ElfXXAddr;
+    #[allow(dead_code)]
     fn p_paddr(&self) -> ElfXXAddr;
     fn p_filesz(&self) -> ElfXXXword;
     fn p_memsz(&self) -> ElfXXXword;
@@ -59,20 +72,84 @@ pub trait ElfXXShdr<ElfXXAddr, ElfXXWord, ElfXXOff, ElfXXXword>: ElfHeader {
     fn sh_entsize(&self) -> ElfXXXword;
 }
 
+pub trait ElfXXSym<ElfXXAddr, ElfXXHalf, ElfXXWord, ElfXXXword>: ElfHeader {
+    fn st_name(&self) -> ElfXXWord;
+    fn st_value(&self) -> ElfXXAddr;
+    fn st_size(&self) -> ElfXXXword;
+    fn st_info(&self) -> u8;
+    fn st_other(&self) -> u8;
+    fn st_shndx(&self) -> ElfXXHalf;
+}
+
+pub trait ElfXXRel<ElfXXAddr, ElfXXXword>: ElfHeader {
+    fn r_offset(&self) -> ElfXXAddr;
+    fn r_info(&self) -> ElfXXXword;
+    fn r_addend(&self) -> Option<i64>;
+}
+
+pub trait ElfXXDyn<ElfXXXword>: ElfHeader {
+    fn d_tag(&self) -> i64;
+    fn d_val(&self) -> ElfXXXword;
+}
+
+// Real counts of program and section headers and index of the section name string table
+pub struct Numbering {
+    phnum: usize,
+    shnum: usize,
+    shstrndx: usize,
+    // header fields whose values had to be taken from section header 0
+    extended: Vec<&'static str>,
+}
+
 macro_rules! read_field {
     ($name:ident, $field:ident) => {
         $name
             .$field()
             .try_into()
-            .map_err(|_| format!("failed to read {}", stringify!($field)))
+            .map_err(|_| ElfError::new(ErrorKind::IntegerOverflow, stringify!($field), None))
     };
 }
 
-pub trait ElfXX<EhdrT, PhdrT, ShdrT, ElfXXAddr, ElfXXHalf, ElfXXWord, ElfXXOff, ElfXXXword>
-where
+// Index of a header as ranges refer to it, or nothing if there are more headers than fit into it
+fn header_index(
+    i: usize,
+    structure: &str,
+    offset: usize,
+    warnings: &mut Vec<ElfError>,
+) -> Option<u32> {
+    let idx = u32::try_from(i).ok();
+
+    if idx.is_none() {
+        warnings.push(
+            ElfError::new(ErrorKind::IntegerOverflow, format!("{} {}", structure, i), Some(offset))
+                .with_detail("too many headers, the rest are left out"),
+        );
+    }
+
+    idx
+}
+
+pub trait ElfXX<
+    EhdrT,
+    PhdrT,
+    ShdrT,
+    SymT,
+    RelT,
+    RelaT,
+    DynT,
+    ElfXXAddr,
+    ElfXXHalf,
+    ElfXXWord,
+    ElfXXOff,
+    ElfXXXword,
+> where
     EhdrT: ElfXXEhdr<ElfXXAddr, ElfXXHalf, ElfXXWord, ElfXXOff>,
     PhdrT: ElfXXPhdr<ElfXXAddr, ElfXXWord, ElfXXOff, ElfXXXword>,
     ShdrT: ElfXXShdr<ElfXXAddr, ElfXXWord, ElfXXOff, ElfXXXword>,
+    SymT: ElfXXSym<ElfXXAddr, ElfXXHalf, ElfXXWord, ElfXXXword>,
+    RelT: ElfXXRel<ElfXXAddr, ElfXXXword>,
+    RelaT: ElfXXRel<ElfXXAddr, ElfXXXword>,
+    DynT: ElfXXDyn<ElfXXXword>,
     u32: From<ElfXXWord>,
     u64: From<ElfXXXword>,
     // This is a bit of a mess
@@ -82,75 +159,124 @@ where
     ElfXXOff: std::convert::TryInto<usize> + std::fmt::Display + std::fmt::LowerHex,
     ElfXXXword: std::convert::TryInto<usize>,
 {
-    fn parse(buf: &[u8], ident: &ParsedIdent, elf: &mut ParsedElf) -> Result<(), String> {
-        let ehdr_size = size_of::<EhdrT>();
+    fn parse(buf: &[u8], ident: &ParsedIdent, elf: &mut ParsedElf) -> Result<(), ElfError> {
+        let ehdr = EhdrT::read(buf, 0, ident.endianness)?;
 
-        if buf.len() < ehdr_size {
-            return Err(String::from("file is smaller than ELF file header"));
-        }
+        let shdr0 = Self::read_first_shdr(buf, ident.endianness, &ehdr)?;
+        let numbering = Self::parse_numbering(&ehdr, shdr0.as_ref())?;
+
+        elf.shstrndx = numbering.shstrndx;
+        elf.machine = ehdr.e_machine().into();
+        elf.entry = read_field!(ehdr, e_entry)?;
+        elf.phdr_table = HeaderTable {
+            offset: read_field!(ehdr, e_phoff)?,
+            entsize: ehdr.e_phentsize().into() as usize,
+            num: numbering.phnum,
+        };
+        elf.shdr_table = HeaderTable {
+            offset: read_field!(ehdr, e_shoff)?,
+            entsize: ehdr.e_shentsize().into() as usize,
+            num: numbering.shnum,
+        };
 
-        let ehdr = EhdrT::from_bytes(&buf[0..ehdr_size], ident.endianness)?;
+        Self::parse_ehdr(&ehdr, &numbering, elf);
 
-        elf.shstrndx = ehdr.e_shstrndx().into();
+        Self::parse_phdrs(buf, ident.endianness, &ehdr, numbering.phnum, elf)?;
 
-        Self::parse_ehdr(&ehdr, elf);
+        Self::parse_shdrs(buf, ident.endianness, &ehdr, numbering.shnum, elf)?;
 
-        Self::parse_phdrs(buf, ident.endianness, &ehdr, elf)?;
+        Self::parse_symtabs(buf, ident.endianness, elf)?;
 
-        Self::parse_shdrs(buf, ident.endianness, &ehdr, elf)?;
+        Self::parse_reltabs(buf, ident.endianness, elf)?;
+
+        Self::parse_dynamic(buf, ident.endianness, elf)?;
 
         Ok(())
     }
 
-    fn parse_ehdr(ehdr: &EhdrT, elf: &mut ParsedElf) {
-        Self::push_ehdr_info(ehdr, &mut elf.information);
+    fn read_first_shdr(
+        buf: &[u8],
+        endianness: u8,
+        ehdr: &EhdrT,
+    ) -> Result<Option<ShdrT>, ElfError> {
+        let start = read_field!(ehdr, e_shoff)?;
+
+        if start == 0 {
+            return Ok(None);
+        }
+
+        // a missing section header table is reported later by parse_shdrs
+        Ok(ShdrT::read(buf, start, endianness).ok())
+    }
+
+    // Counts that don't fit into the file header are stored in section header 0 instead
+    fn parse_numbering(ehdr: &EhdrT, shdr0: Option<&ShdrT>) -> Result<Numbering, ElfError> {
+        let mut numbering = Numbering {
+            phnum: ehdr.e_phnum().into() as usize,
+            shnum: ehdr.e_shnum().into() as usize,
+            shstrndx: ehdr.e_shstrndx().into() as usize,
+            extended: vec![],
+        };
+
+        let shdr0 = match shdr0 {
+            Some(shdr0) => shdr0,
+            None => return Ok(numbering),
+        };
+
+        if numbering.phnum == PN_XNUM as usize {
+            numbering.phnum = read_field!(shdr0, sh_info)?;
+            numbering.extended.push("e_phnum (sh_info)");
+        }
+
+        if numbering.shnum == 0 {
+            numbering.shnum = read_field!(shdr0, sh_size)?;
+
+            if numbering.shnum != 0 {
+                numbering.extended.push("e_shnum (sh_size)");
+            }
+        }
+
+        if numbering.shstrndx == SHN_XINDEX as usize {
+            numbering.shstrndx = read_field!(shdr0, sh_link)?;
+            numbering.extended.push("e_shstrndx (sh_link)");
+        }
+
+        Ok(numbering)
+    }
+
+    fn parse_ehdr(ehdr: &EhdrT, numbering: &Numbering, elf: &mut ParsedElf) {
+        Self::push_ehdr_info(ehdr, numbering, &mut elf.information);
 
         Self::add_ehdr_ranges(ehdr, &mut elf.ranges);
     }
 
-    fn push_ehdr_info(ehdr: &EhdrT, information: &mut Vec<InfoTuple>) {
+    fn push_ehdr_info(ehdr: &EhdrT, numbering: &Numbering, information: &mut Vec<InfoTuple>) {
         information.push(("e_type", "Type", type_to_string(ehdr.e_type().into())));
 
         information.push(("e_machine", "Architecture", machine_to_string(ehdr.e_machine().into())));
 
-        information.push((
-            "e_entry",
-            "Entrypoint",
-            format!("<span class='number' title='{}'>{:#x}</span>", ehdr.e_entry(), ehdr.e_entry()),
-        ));
+        information.push(("e_entry", "Entrypoint", format!("{:#x}", ehdr.e_entry())));
 
         information.push((
             "ph",
             "Program headers",
-            format!(
-                "<span title='{:#x}' class='number fileinfo_e_phnum'>{}</span> * \
-                 <span title='{:#x}' class='number fileinfo_e_phentsize'>{}</span> @ \
-                 <span title='{:#x}' class='number fileinfo_e_phoff'>{}</span>",
-                ehdr.e_phnum(),
-                ehdr.e_phnum(),
-                ehdr.e_phentsize(),
-                ehdr.e_phentsize(),
-                ehdr.e_phoff(),
-                ehdr.e_phoff()
-            ),
+            format!("{} * {} @ {}", numbering.phnum, ehdr.e_phentsize(), ehdr.e_phoff()),
         ));
 
         information.push((
             "sh",
             "Section headers",
-            format!(
-                "<span title='{:#x}' class='number fileinfo_e_shnum'>{}</span> * \
-                 <span title='{:#x}' class='number fileinfo_e_shentsize'>{}</span> @ \
-                 <span title='{:#x}' class='number fileinfo_e_shoff'>{}</span>",
-                ehdr.e_shnum(),
-                ehdr.e_shnum(),
-                ehdr.e_shentsize(),
-                ehdr.e_shentsize(),
-                ehdr.e_shoff(),
-                ehdr.e_shoff()
-            ),
+            format!("{} * {} @ {}", numbering.shnum, ehdr.e_shentsize(), ehdr.e_shoff()),
         ));
 
+        if !numbering.extended.is_empty() {
+            information.push((
+                "ext_num",
+                "Extended numbering",
+                format!("{} of section 0", numbering.extended.join(", ")),
+            ));
+        }
+
         if u32::from(ehdr.e_flags()) != 0 {
             information.push(("e_flags", "Flags", format!("{:#x}", ehdr.e_flags())));
         }
@@ -162,21 +288,51 @@ where
         buf: &[u8],
         endianness: u8,
         ehdr: &EhdrT,
+        phnum: usize,
         elf: &mut ParsedElf,
-    ) -> Result<(), String> {
+    ) -> Result<(), ElfError> {
         let mut start = read_field!(ehdr, e_phoff)?;
         let phsize = size_of::<PhdrT>();
 
-        for i in 0..ehdr.e_phnum().into() {
-            let phdr = PhdrT::from_bytes(&buf[start..start + phsize], endianness)?;
+        for idx in 0..phnum {
+            let i = match header_index(idx, "program header", start, &mut elf.warnings) {
+                Some(i) => i,
+                None => break,
+            };
+            let phdr = match PhdrT::read(buf, start, endianness) {
+                Ok(phdr) => phdr,
+                Err(_) => {
+                    elf.warnings.push(ElfError::new(
+                        ErrorKind::Truncated,
+                        format!("program header {}", i),
+                        Some(start),
+                    ));
+                    break;
+                }
+            };
+
             let parsed = Self::parse_phdr(&phdr)?;
-            let ranges = &mut elf.ranges;
 
-            if parsed.file_offset != 0 && parsed.file_size != 0 {
-                ranges.add_range(parsed.file_offset, parsed.file_size, RangeType::Segment(i));
+            if file_slice(buf, parsed.file_offset, parsed.file_size).is_none() {
+                elf.warnings.push(
+                    ElfError::new(
+                        ErrorKind::OutOfRange,
+                        format!("segment {}", i),
+                        Some(parsed.file_offset),
+                    )
+                    .with_detail(format!(
+                        "{:#x} bytes extend past the end of file",
+                        parsed.file_size
+                    )),
+                );
+            } else if parsed.file_offset != 0 && parsed.file_size != 0 {
+                elf.ranges
+                    .add_range(parsed.file_offset, parsed.file_size, RangeType::Segment(i));
             }
 
-            ranges.add_range(start, phsize, RangeType::ProgramHeader(i as u32));
+            let ranges = &mut elf.ranges;
+
+            ranges.add_range(start, phsize, RangeType::ProgramHeader(i));
 
             Self::add_phdr_ranges(start, ranges);
 
@@ -188,7 +344,7 @@ where
         Ok(())
     }
 
-    fn parse_phdr(phdr: &PhdrT) -> Result<ParsedPhdr, String> {
+    fn parse_phdr(phdr: &PhdrT) -> Result<ParsedPhdr, ElfError> {
         let file_offset = read_field!(phdr, p_offset)?;
         let file_size = read_field!(phdr, p_filesz)?;
         let vaddr = read_field!(phdr, p_vaddr)?;
@@ -212,21 +368,49 @@ where
         buf: &[u8],
         endianness: u8,
         ehdr: &EhdrT,
+        shnum: usize,
         elf: &mut ParsedElf,
-    ) -> Result<(), String> {
+    ) -> Result<(), ElfError> {
         let mut start = read_field!(ehdr, e_shoff)?;
         let shsize = size_of::<ShdrT>();
 
-        for i in 0..ehdr.e_shnum().into() {
-            let shdr = ShdrT::from_bytes(&buf[start..start + shsize], endianness)?;
-            let parsed = Self::parse_shdr(buf, endianness, &shdr)?;
-            let ranges = &mut elf.ranges;
+        for idx in 0..shnum {
+            let i = match header_index(idx, "section header", start, &mut elf.warnings) {
+                Some(i) => i,
+                None => break,
+            };
+            let shdr = match ShdrT::read(buf, start, endianness) {
+                Ok(shdr) => shdr,
+                Err(_) => {
+                    elf.warnings.push(ElfError::new(
+                        ErrorKind::Truncated,
+                        format!("section header {}", i),
+                        Some(start),
+                    ));
+                    break;
+                }
+            };
 
-            if parsed.file_offset != 0 && parsed.size != 0 && parsed.shtype != SHT_NOBITS {
-                ranges.add_range(parsed.file_offset, parsed.size, RangeType::Section(i));
+            let parsed = Self::parse_shdr(buf, endianness, &shdr)?;
+            let in_file = parsed.shtype != SHT_NOBITS;
+
+            if in_file && file_slice(buf, parsed.file_offset, parsed.size).is_none() {
+                elf.warnings.push(
+                    ElfError::new(
+                        ErrorKind::OutOfRange,
+                        format!("section {}", i),
+                        Some(parsed.file_offset),
+                    )
+                    .with_detail(format!("{:#x} bytes extend past the end of file", parsed.size)),
+                );
+            } else if parsed.file_offset != 0 && parsed.size != 0 && in_file {
+                elf.ranges
+                    .add_range(parsed.file_offset, parsed.size, RangeType::Section(i));
             }
 
-            ranges.add_range(start, shsize, RangeType::SectionHeader(i as u32));
+            let ranges = &mut elf.ranges;
+
+            ranges.add_range(start, shsize, RangeType::SectionHeader(i));
 
             Self::add_shdr_ranges(start, ranges);
 
@@ -238,7 +422,7 @@ where
         Ok(())
     }
 
-    fn parse_shdr(_buf: &[u8], _endianness: u8, shdr: &ShdrT) -> Result<ParsedShdr, String> {
+    fn parse_shdr(_buf: &[u8], _endianness: u8, shdr: &ShdrT) -> Result<ParsedShdr, ElfError> {
         let name = read_field!(shdr, sh_name)?;
         let addr = read_field!(shdr, sh_addr)?;
         let file_offset = read_field!(shdr, sh_offset)?;
@@ -263,4 +447,189 @@ where
     }
 
     fn add_shdr_ranges(start: usize, ranges: &mut Ranges);
+
+    fn parse_symtabs(buf: &[u8], endianness: u8, elf: &mut ParsedElf) -> Result<(), ElfError> {
+        let symsize = size_of::<SymT>();
+
+        for (i, shdr) in elf.shdrs.iter().enumerate() {
+            if shdr.shtype != SHT_SYMTAB && shdr.shtype != SHT_DYNSYM {
+                continue;
+            }
+
+            let section = match file_slice(buf, shdr.file_offset, shdr.size) {
+                Some(section) => section,
+                None => continue,
+            };
+
+            let strtab = match elf.shdrs.get(shdr.link) {
+                Some(strtab) => {
+                    StrTab::new(file_slice(buf, strtab.file_offset, strtab.size).unwrap_or(&[]))
+                }
+                None => {
+                    elf.warnings.push(
+                        ElfError::new(
+                            ErrorKind::OutOfRange,
+                            format!("sh_link of section {}", i),
+                            None,
+                        )
+                        .with_detail(format!("there is no section {}", shdr.link)),
+                    );
+
+                    StrTab::new(&[])
+                }
+            };
+
+            let mut syms = vec![];
+            let mut start = shdr.file_offset;
+
+            for bytes in section.chunks_exact(symsize) {
+                let sym = SymT::from_bytes(bytes, endianness)?;
+                let parsed = Self::parse_sym(&sym, &strtab)?;
+
+                elf.ranges.add_range(start, symsize, RangeType::Symbol);
+
+                Self::add_sym_ranges(start, &mut elf.ranges);
+
+                syms.push(parsed);
+
+                start += symsize;
+            }
+
+            elf.symtabs.push(ParsedSymTab { section: i, syms });
+        }
+
+        Ok(())
+    }
+
+    fn parse_sym(sym: &SymT, strtab: &StrTab) -> Result<ParsedSym, ElfError> {
+        let name_idx = read_field!(sym, st_name)?;
+        let value = read_field!(sym, st_value)?;
+        let size = read_field!(sym, st_size)?;
+
+        Ok(ParsedSym {
+            name: strtab.get(name_idx).to_string(),
+            name_offset: name_idx,
+            value,
+            size,
+            bind: sym.st_info() >> 4,
+            stype: sym.st_info() & 0xf,
+            visibility: sym.st_other() & 0x3,
+            shndx: sym.st_shndx().into(),
+            ext_shndx: None,
+            versym: None,
+            version: None,
+        })
+    }
+
+    fn add_sym_ranges(start: usize, ranges: &mut Ranges);
+
+    fn parse_reltabs(buf: &[u8], endianness: u8, elf: &mut ParsedElf) -> Result<(), ElfError> {
+        for (i, shdr) in elf.shdrs.iter().enumerate() {
+            let section = match file_slice(buf, shdr.file_offset, shdr.size) {
+                Some(section) => section,
+                None => continue,
+            };
+
+            let (start, ranges) = (shdr.file_offset, &mut elf.ranges);
+
+            let rels = match shdr.shtype {
+                SHT_REL => Self::parse_rels::<RelT>(section, start, endianness, ranges)?,
+                SHT_RELA => Self::parse_rels::<RelaT>(section, start, endianness, ranges)?,
+                _ => continue,
+            };
+
+            elf.reltabs.push(ParsedRelTab { section: i, rels });
+        }
+
+        Ok(())
+    }
+
+    fn parse_rels<T: ElfXXRel<ElfXXAddr, ElfXXXword>>(
+        section: &[u8],
+        section_start: usize,
+        endianness: u8,
+        ranges: &mut Ranges,
+    ) -> Result<Vec<ParsedRel>, ElfError> {
+        let relsize = size_of::<T>();
+        let mut rels = vec![];
+
+        for (i, bytes) in section.chunks_exact(relsize).enumerate() {
+            let start = i * relsize;
+            let rel = T::from_bytes(bytes, endianness)?;
+            let parsed = Self::parse_rel(&rel)?;
+
+            ranges.add_range(section_start + start, relsize, RangeType::Relocation);
+
+            Self::add_rel_ranges(section_start + start, parsed.addend.is_some(), ranges);
+
+            rels.push(parsed);
+        }
+
+        Ok(rels)
+    }
+
+    fn parse_rel<T: ElfXXRel<ElfXXAddr, ElfXXXword>>(rel: &T) -> Result<ParsedRel, ElfError> {
+        let offset = read_field!(rel, r_offset)?;
+        let info = u64::from(rel.r_info());
+
+        Ok(ParsedRel {
+            offset,
+            sym: Self::r_sym(info),
+            rtype: Self::r_type(info),
+            addend: rel.r_addend(),
+        })
+    }
+
+    fn add_rel_ranges(start: usize, has_addend: bool, ranges: &mut Ranges);
+
+    fn parse_dynamic(buf: &[u8], endianness: u8, elf: &mut ParsedElf) -> Result<(), ElfError> {
+        let section = elf.shdrs.iter().find(|shdr| shdr.shtype == SHT_DYNAMIC);
+        let segment = elf.phdrs.iter().find(|phdr| phdr.ptype == PT_DYNAMIC);
+
+        // prefer the section, but fall back to the segment for binaries without section headers
+        let (area_start, area_size) = match (section, segment) {
+            (Some(shdr), _) => (shdr.file_offset, shdr.size),
+            (None, Some(phdr)) => (phdr.file_offset, phdr.file_size),
+            (None, None) => return Ok(()),
+        };
+
+        let area = match file_slice(buf, area_start, area_size) {
+            Some(area) => area,
+            None => return Ok(()),
+        };
+
+        let dynsize = size_of::<DynT>();
+        let mut start = area_start;
+
+        for (i, bytes) in area.chunks_exact(dynsize).enumerate() {
+            let entry = DynT::from_bytes(bytes, endianness)?;
+            let tag = entry.d_tag();
+
+            elf.ranges
+                .add_range(start, dynsize, RangeType::Dynamic(i as u32));
+
+            Self::add_dyn_ranges(start, &mut elf.ranges);
+
+            elf.dynamic.push(ParsedDyn {
+                tag,
+                value: entry.d_val().into(),
+                string: None,
+                file_offset: None,
+            });
+
+            if tag == DT_NULL {
+                break;
+            }
+
+            start += dynsize;
+        }
+
+        Ok(())
+    }
+
+    fn add_dyn_ranges(start: usize, ranges: &mut Ranges);
+
+    fn r_sym(info: u64) -> u32;
+
+    fn r_type(info: u64) -> u32;
 }
@@ -0,0 +1,78 @@
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    // a structure doesn't fit into the file
+    Truncated,
+    BadMagic,
+    UnsupportedClass,
+    UnsupportedEncoding,
+    // a value doesn't fit into the host's integer types
+    IntegerOverflow,
+    // an offset, size or index points outside of what it refers to
+    OutOfRange,
+}
+
+// Fatal when returned from parsing, non-fatal when stored in `ParsedElf::warnings`
+#[derive(Clone, Debug)]
+pub struct ElfError {
+    pub kind: ErrorKind,
+    // name of the structure or field, such as "program header 3" or "e_shstrndx"
+    pub structure: String,
+    // offset in file of the structure, if known
+    pub offset: Option<usize>,
+    pub detail: String,
+}
+
+impl ErrorKind {
+    fn describe(self) -> &'static str {
+        match self {
+            ErrorKind::Truncated => "truncated",
+            ErrorKind::BadMagic => "bad magic",
+            ErrorKind::UnsupportedClass => "unsupported class",
+            ErrorKind::UnsupportedEncoding => "unsupported data encoding",
+            ErrorKind::IntegerOverflow => "integer overflow",
+            ErrorKind::OutOfRange => "out of range",
+        }
+    }
+}
+
+impl ElfError {
+    pub fn new<S: Into<String>>(kind: ErrorKind, structure: S, offset: Option<usize>) -> ElfError {
+        ElfError {
+            kind,
+            structure: structure.into(),
+            offset,
+            detail: String::new(),
+        }
+    }
+
+    pub fn with_detail<S: Into<String>>(mut self, detail: S) -> ElfError {
+        self.detail = detail.into();
+        self
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.describe())
+    }
+}
+
+impl fmt::Display for ElfError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.kind, self.structure)?;
+
+        if let Some(offset) = self.offset {
+            write!(f, " at {:#x}", offset)?;
+        }
+
+        if !self.detail.is_empty() {
+            write!(f, " ({})", self.detail)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ElfError {}
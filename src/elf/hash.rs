@@ -0,0 +1,606 @@
+// Symbol hash tables: SysV .hash and GNU .gnu.hash. Both consist of 32-bit words, except for
+// the GNU bloom filter whose words are as wide as an address.
+
+use super::defs::*;
+use super::parser::*;
+use std::ops::Range;
+
+const SYSV_HEADER_SIZE: usize = 8;
+const GNU_HEADER_SIZE: usize = 16;
+
+// Reads `count` words starting at `offset`, or nothing if the section is too short
+fn read_words(
+    section: &[u8],
+    offset: usize,
+    count: usize,
+    size: usize,
+    endianness: u8,
+) -> Option<Vec<u64>> {
+    let bytes = section.get(offset..offset.checked_add(count.checked_mul(size)?)?)?;
+
+    let words = bytes
+        .chunks_exact(size)
+        .map(|chunk| {
+            let mut buf = [0; 8];
+
+            if endianness == ELF_DATA2LSB {
+                buf[..size].copy_from_slice(chunk);
+                u64::from_le_bytes(buf)
+            } else {
+                buf[8 - size..].copy_from_slice(chunk);
+                u64::from_be_bytes(buf)
+            }
+        })
+        .collect();
+
+    Some(words)
+}
+
+// Names are hashed as raw bytes, which don't have to be valid UTF-8
+fn sysv_hash(name: &[u8]) -> u32 {
+    let mut h: u32 = 0;
+
+    for &c in name {
+        h = (h << 4).wrapping_add(c as u32);
+
+        let g = h & 0xf000_0000;
+
+        if g != 0 {
+            h ^= g >> 24;
+        }
+
+        h &= !g;
+    }
+
+    h
+}
+
+fn gnu_hash(name: &[u8]) -> u32 {
+    name.iter()
+        .fold(5381u32, |h, &c| h.wrapping_mul(33).wrapping_add(c as u32))
+}
+
+// Symbols that are expected to be found by the dynamic linker through the hash table
+fn is_exported(idx: usize, sym: &ParsedSym) -> bool {
+    idx != 0 && sym.shndx != SHN_UNDEF && sym.bind != STB_LOCAL
+}
+
+fn add_word_ranges(
+    start: usize,
+    count: usize,
+    size: usize,
+    field: &'static str,
+    ranges: &mut Ranges,
+) {
+    for i in 0..count {
+        ranges.add_range(start + i * size, size, RangeType::HashField(field));
+    }
+}
+
+struct SysvTable {
+    buckets: Vec<u64>,
+    chains: Vec<u64>,
+}
+
+impl SysvTable {
+    // Symbol index which a bucket or a chain entry links to; zero or an out-of-bounds link ends
+    // the chain
+    fn link(&self, value: u64) -> Option<usize> {
+        let idx = value as usize;
+
+        (idx != 0 && idx < self.chains.len()).then_some(idx)
+    }
+
+    fn start(&self, bucket: usize) -> Option<usize> {
+        self.link(self.buckets[bucket])
+    }
+
+    fn next(&self, idx: usize) -> Option<usize> {
+        self.link(self.chains[idx])
+    }
+
+    // Number of distinct symbols reachable from each chain entry, stopping at cycles. Every entry
+    // is followed once, so that buckets which share a long chain don't take quadratic time.
+    fn chain_lengths(&self) -> Vec<usize> {
+        let mut lengths = vec![0; self.chains.len()];
+        let mut done = vec![false; self.chains.len()];
+        let mut path_pos = vec![None; self.chains.len()];
+        let mut path = vec![];
+
+        for first in 1..self.chains.len() {
+            let mut idx = Some(first);
+            let mut length = 0;
+
+            // follow the chain until its end, an entry with a known length or a cycle
+            while let Some(i) = idx {
+                if done[i] {
+                    length = lengths[i];
+                    break;
+                }
+
+                if let Some(pos) = path_pos[i] {
+                    // every entry of a cycle reaches the whole cycle
+                    let cycle = path.split_off(pos);
+
+                    for &j in &cycle {
+                        lengths[j] = cycle.len();
+                        done[j] = true;
+                    }
+
+                    length = cycle.len();
+                    break;
+                }
+
+                path_pos[i] = Some(path.len());
+                path.push(i);
+                idx = self.next(i);
+            }
+
+            for &j in path.iter().rev() {
+                length += 1;
+                lengths[j] = length;
+                done[j] = true;
+            }
+
+            path.clear();
+        }
+
+        lengths
+    }
+
+    // Symbols which are in the chain of the bucket of their hash, and the number of chains which
+    // loop or run into other chains. Every entry is visited once, so symbols past the point where
+    // a chain runs into another one are only checked against the first chain.
+    fn reachable(&self, hash_bucket: impl Fn(usize) -> Option<usize>) -> (Vec<bool>, usize) {
+        let mut reachable = vec![false; self.chains.len()];
+        let mut visited = vec![false; self.chains.len()];
+        let mut tangled = 0;
+
+        for bucket in 0..self.buckets.len() {
+            let mut idx = self.start(bucket);
+
+            while let Some(i) = idx {
+                if visited[i] {
+                    tangled += 1;
+                    break;
+                }
+
+                visited[i] = true;
+
+                // a symbol hashed into the wrong bucket is never found by the dynamic linker
+                if hash_bucket(i) == Some(bucket) {
+                    reachable[i] = true;
+                }
+
+                idx = self.next(i);
+            }
+        }
+
+        (reachable, tangled)
+    }
+}
+
+struct GnuTable {
+    symoffset: usize,
+    bloom_shift: u32,
+    bloom_bits: u32,
+    bloom: Vec<u64>,
+    buckets: Vec<u64>,
+    chains: Vec<u64>,
+    chain_ends: Vec<usize>,
+}
+
+// Index of the last entry of the chain which each entry of the chain array belongs to. A set low
+// bit of the hash ends a chain, and so does the end of the array.
+fn gnu_chain_ends(chains: &[u64]) -> Vec<usize> {
+    let mut ends = vec![0; chains.len()];
+
+    for i in (0..chains.len()).rev() {
+        ends[i] = if chains[i] & 1 != 0 || i + 1 == chains.len() {
+            i
+        } else {
+            ends[i + 1]
+        };
+    }
+
+    ends
+}
+
+impl GnuTable {
+    // Symbol indices of a bucket, which are consecutive
+    fn chain(&self, bucket: usize) -> Range<usize> {
+        let start = self.buckets[bucket] as usize;
+
+        if start == 0 || start < self.symoffset || start - self.symoffset >= self.chains.len() {
+            return 0..0;
+        }
+
+        start..self.symoffset + self.chain_ends[start - self.symoffset] + 1
+    }
+
+    fn bloom_allows(&self, hash: u32) -> bool {
+        if self.bloom.is_empty() {
+            return false;
+        }
+
+        let word = self.bloom[(hash / self.bloom_bits) as usize % self.bloom.len()];
+        let bit1 = hash % self.bloom_bits;
+        let bit2 = hash.checked_shr(self.bloom_shift).unwrap_or(0) % self.bloom_bits;
+
+        word & (1 << bit1) != 0 && word & (1 << bit2) != 0
+    }
+
+    fn lookup(&self, idx: usize, hash: u32) -> bool {
+        if !self.bloom_allows(hash) || self.buckets.is_empty() {
+            return false;
+        }
+
+        let bucket = hash as usize % self.buckets.len();
+
+        self.chain(bucket).contains(&idx)
+            && self.chains[idx - self.symoffset] as u32 | 1 == hash | 1
+    }
+}
+
+impl ParsedElf<'_> {
+    pub(super) fn parse_hash_tables(&mut self, class: u8, endianness: u8) {
+        for idx in 0..self.shdrs.len() {
+            match self.shdrs[idx].shtype {
+                SHT_HASH => self.parse_sysv_hash(idx, endianness),
+                SHT_GNU_HASH => self.parse_gnu_hash(idx, class, endianness),
+                _ => {}
+            }
+        }
+    }
+
+    fn linked_syms(&self, idx: usize) -> &[ParsedSym] {
+        self.symtabs
+            .iter()
+            .find(|symtab| symtab.section == self.shdrs[idx].link)
+            .map(|symtab| symtab.syms.as_slice())
+            .unwrap_or(&[])
+    }
+
+    // String table of the symbol table which a hash table is linked to
+    fn linked_sym_names(&self, idx: usize) -> StrTab<'_> {
+        match self.shdrs.get(self.shdrs[idx].link) {
+            Some(symtab) if symtab.link < self.shdrs.len() => {
+                StrTab::new(self.section_bytes(symtab.link))
+            }
+            _ => StrTab::new(&[]),
+        }
+    }
+
+    fn parse_sysv_hash(&mut self, idx: usize, endianness: u8) {
+        let section = self.section_bytes(idx);
+        let base = self.shdrs[idx].file_offset;
+        let syms = self.linked_syms(idx);
+        let names = self.linked_sym_names(idx);
+
+        let mut table = ParsedHashTab {
+            section: idx,
+            gnu: false,
+            nbuckets: 0,
+            symoffset: 0,
+            bloom_size: 0,
+            bloom_shift: 0,
+            chain_lengths: vec![],
+            unreachable: vec![],
+            problems: vec![],
+        };
+
+        let header = match read_words(section, 0, 2, 4, endianness) {
+            Some(header) => header,
+            None => {
+                table
+                    .problems
+                    .push(String::from("section is too small for a header"));
+                self.hashtabs.push(table);
+                return;
+            }
+        };
+
+        let (nbucket, nchain) = (header[0] as usize, header[1] as usize);
+        let buckets_start = SYSV_HEADER_SIZE;
+        let chains_start = buckets_start.saturating_add(nbucket.saturating_mul(4));
+
+        table.nbuckets = nbucket;
+
+        let buckets = read_words(section, buckets_start, nbucket, 4, endianness);
+        let chains = read_words(section, chains_start, nchain, 4, endianness);
+
+        let sysv = match (buckets, chains) {
+            (Some(buckets), Some(chains)) => SysvTable { buckets, chains },
+            _ => {
+                table
+                    .problems
+                    .push(String::from("section is too small for nbucket and nchain"));
+                self.hashtabs.push(table);
+                return;
+            }
+        };
+
+        if nchain != syms.len() {
+            table.problems.push(format!(
+                "nchain is {}, but the symbol table has {} entries",
+                nchain,
+                syms.len()
+            ));
+        }
+
+        let lengths = sysv.chain_lengths();
+
+        for bucket in 0..nbucket {
+            table
+                .chain_lengths
+                .push(sysv.start(bucket).map_or(0, |start| lengths[start]));
+        }
+
+        let (reachable, tangled) = sysv.reachable(|i| {
+            syms.get(i)
+                .map(|sym| sysv_hash(names.get_bytes(sym.name_offset)) as usize % nbucket)
+        });
+
+        if tangled > 0 {
+            table.problems.push(format!(
+                "{} chains loop or run into other chains, symbols past that point are not checked",
+                tangled
+            ));
+        }
+
+        for (i, sym) in syms.iter().enumerate() {
+            if is_exported(i, sym) && !reachable.get(i).copied().unwrap_or(false) {
+                table.unreachable.push(sym.name.clone());
+            }
+        }
+
+        self.ranges
+            .add_range(base, SYSV_HEADER_SIZE, RangeType::HashHeader);
+        self.ranges
+            .add_range(base, 4, RangeType::HashField("nbucket"));
+        self.ranges
+            .add_range(base + 4, 4, RangeType::HashField("nchain"));
+
+        add_word_ranges(base + buckets_start, nbucket, 4, "bucket", &mut self.ranges);
+        add_word_ranges(base + chains_start, nchain, 4, "chain", &mut self.ranges);
+
+        self.hashtabs.push(table);
+    }
+
+    fn parse_gnu_hash(&mut self, idx: usize, class: u8, endianness: u8) {
+        let section = self.section_bytes(idx);
+        let base = self.shdrs[idx].file_offset;
+        let syms = self.linked_syms(idx);
+        let names = self.linked_sym_names(idx);
+        let bloom_word_size = if class == ELF_CLASS32 { 4 } else { 8 };
+
+        let mut table = ParsedHashTab {
+            section: idx,
+            gnu: true,
+            nbuckets: 0,
+            symoffset: 0,
+            bloom_size: 0,
+            bloom_shift: 0,
+            chain_lengths: vec![],
+            unreachable: vec![],
+            problems: vec![],
+        };
+
+        let header = match read_words(section, 0, 4, 4, endianness) {
+            Some(header) => header,
+            None => {
+                table
+                    .problems
+                    .push(String::from("section is too small for a header"));
+                self.hashtabs.push(table);
+                return;
+            }
+        };
+
+        let (nbuckets, symoffset) = (header[0] as usize, header[1] as usize);
+        let (bloom_size, bloom_shift) = (header[2] as usize, header[3] as u32);
+
+        table.nbuckets = nbuckets;
+        table.symoffset = symoffset;
+        table.bloom_size = bloom_size;
+        table.bloom_shift = bloom_shift;
+
+        let bloom_start = GNU_HEADER_SIZE;
+        let buckets_start = bloom_start.saturating_add(bloom_size.saturating_mul(bloom_word_size));
+        let chains_start = buckets_start.saturating_add(nbuckets.saturating_mul(4));
+
+        // the chain array has no explicit length, it covers all symbols starting at symoffset
+        let nchains = syms.len().saturating_sub(symoffset);
+
+        let bloom = read_words(section, bloom_start, bloom_size, bloom_word_size, endianness);
+        let buckets = read_words(section, buckets_start, nbuckets, 4, endianness);
+        let chains = read_words(section, chains_start, nchains, 4, endianness);
+
+        let gnu = match (bloom, buckets, chains) {
+            (Some(bloom), Some(buckets), Some(chains)) => GnuTable {
+                symoffset,
+                bloom_shift,
+                bloom_bits: bloom_word_size as u32 * 8,
+                bloom,
+                buckets,
+                chain_ends: gnu_chain_ends(&chains),
+                chains,
+            },
+            _ => {
+                table
+                    .problems
+                    .push(String::from("section is too small for the declared arrays"));
+                self.hashtabs.push(table);
+                return;
+            }
+        };
+
+        if !bloom_size.is_power_of_two() {
+            table
+                .problems
+                .push(String::from("bloom_size is not a power of 2"));
+        }
+
+        if symoffset > syms.len() {
+            table.problems.push(format!(
+                "symoffset is {}, but the symbol table has {} entries",
+                symoffset,
+                syms.len()
+            ));
+        }
+
+        for bucket in 0..nbuckets {
+            table.chain_lengths.push(gnu.chain(bucket).len());
+        }
+
+        for (i, sym) in syms.iter().enumerate() {
+            let hash = gnu_hash(names.get_bytes(sym.name_offset));
+
+            if is_exported(i, sym) && (i < symoffset || !gnu.lookup(i, hash)) {
+                table.unreachable.push(sym.name.clone());
+            }
+        }
+
+        self.ranges
+            .add_range(base, GNU_HEADER_SIZE, RangeType::HashHeader);
+        self.ranges
+            .add_range(base, 4, RangeType::HashField("nbuckets"));
+        self.ranges
+            .add_range(base + 4, 4, RangeType::HashField("symoffset"));
+        self.ranges
+            .add_range(base + 8, 4, RangeType::HashField("bloom_size"));
+        self.ranges
+            .add_range(base + 12, 4, RangeType::HashField("bloom_shift"));
+
+        add_word_ranges(base + bloom_start, bloom_size, bloom_word_size, "bloom", &mut self.ranges);
+        add_word_ranges(base + buckets_start, nbuckets, 4, "bucket", &mut self.ranges);
+        add_word_ranges(base + chains_start, nchains, 4, "chain", &mut self.ranges);
+
+        self.hashtabs.push(table);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sysv_table(buckets: &[u64], chains: &[u64]) -> SysvTable {
+        SysvTable {
+            buckets: buckets.to_vec(),
+            chains: chains.to_vec(),
+        }
+    }
+
+    // The bloom filter lets everything through, so that only the chains are checked
+    fn gnu_table(symoffset: usize, buckets: &[u64], chains: &[u64]) -> GnuTable {
+        GnuTable {
+            symoffset,
+            bloom_shift: 6,
+            bloom_bits: 64,
+            bloom: vec![u64::MAX],
+            buckets: buckets.to_vec(),
+            chains: chains.to_vec(),
+            chain_ends: gnu_chain_ends(chains),
+        }
+    }
+
+    #[test]
+    fn hashes() {
+        assert_eq!(sysv_hash(b""), 0);
+        assert_eq!(sysv_hash(b"printf"), 0x0779_05a6);
+        assert_eq!(gnu_hash(b""), 5381);
+        assert_eq!(gnu_hash(b"printf"), 0x156b_2bb8);
+    }
+
+    #[test]
+    fn names_which_are_not_utf8() {
+        let names = StrTab::new(b"\0f\xffo\0");
+
+        assert_eq!(names.get(1), "");
+        assert_eq!(names.get_bytes(1), b"f\xffo");
+        assert_ne!(gnu_hash(names.get_bytes(1)), gnu_hash(b""));
+        assert_ne!(sysv_hash(names.get_bytes(1)), sysv_hash(b""));
+    }
+
+    #[test]
+    fn sysv_chain_lengths() {
+        // 1 -> 3, 2, a cycle 4 -> 5 -> 4, 6 -> 4 into the cycle, 7 -> out of bounds
+        let table = sysv_table(&[1, 2, 0], &[0, 3, 0, 0, 5, 4, 4, 100]);
+        let lengths = table.chain_lengths();
+
+        assert_eq!(lengths[1..], [2, 1, 1, 2, 2, 3, 1]);
+        assert_eq!(table.start(2), None);
+    }
+
+    #[test]
+    fn sysv_reachable() {
+        // symbol 3 is in the chain of bucket 0 but hashes into bucket 1
+        let table = sysv_table(&[1, 2], &[0, 3, 0, 0]);
+        let buckets = [None, Some(0), Some(1), Some(1)];
+        let (reachable, tangled) = table.reachable(|i| buckets[i]);
+
+        assert_eq!(reachable, [false, true, true, false]);
+        assert_eq!(tangled, 0);
+    }
+
+    #[test]
+    fn sysv_tangled_chains() {
+        // a cycle 1 -> 2 -> 1, and bucket 1 runs into it
+        let table = sysv_table(&[1, 3], &[0, 2, 1, 2]);
+        let (reachable, tangled) = table.reachable(|i| Some(i % 2));
+
+        assert_eq!(reachable, [false, false, true, true]);
+        assert_eq!(tangled, 2);
+    }
+
+    #[test]
+    fn sysv_shared_chain_is_linear() {
+        let count = 200_000;
+        let chains: Vec<u64> = (0..count as u64).map(|i| (i + 1) % count as u64).collect();
+        let table = sysv_table(&vec![1; count], &chains);
+
+        assert_eq!(table.chain_lengths()[1], count - 1);
+        assert_eq!(table.reachable(|_| Some(0)).1, count - 1);
+    }
+
+    #[test]
+    fn gnu_chain_ends_at_low_bit() {
+        assert_eq!(gnu_chain_ends(&[2, 4, 5, 6, 7, 8]), [2, 2, 2, 4, 4, 5]);
+        assert!(gnu_chain_ends(&[]).is_empty());
+    }
+
+    #[test]
+    fn gnu_chains() {
+        // buckets which are empty, below symoffset, past the chains and one of each size
+        let table = gnu_table(3, &[0, 1, 9, 3, 5], &[0x10, 0x21, 0x31]);
+
+        assert_eq!(table.chain(0), 0..0);
+        assert_eq!(table.chain(1), 0..0);
+        assert_eq!(table.chain(2), 0..0);
+        assert_eq!(table.chain(3), 3..5);
+        assert_eq!(table.chain(4), 5..6);
+    }
+
+    #[test]
+    fn gnu_lookup() {
+        let table = gnu_table(3, &[3, 5], &[0x10, 0x21, 0x31]);
+
+        assert!(table.lookup(3, 0x10));
+        assert!(table.lookup(4, 0x20));
+        assert!(table.lookup(5, 0x31));
+        // wrong bucket, wrong hash, and a symbol before symoffset
+        assert!(!table.lookup(5, 0x30));
+        assert!(!table.lookup(3, 0x12));
+        assert!(!table.lookup(2, 0x10));
+    }
+
+    #[test]
+    fn gnu_bloom_filter() {
+        let mut table = gnu_table(1, &[1], &[0x11]);
+
+        assert!(table.lookup(1, 0x11));
+
+        table.bloom = vec![0];
+        assert!(!table.lookup(1, 0x11));
+    }
+}
@@ -2,4 +2,7 @@ pub mod defs;
 mod elf32;
 mod elf64;
 mod elfxx;
+pub mod error;
+mod hash;
 pub mod parser;
+mod version;
@@ -2,6 +2,7 @@ use super::defs::*;
 use super::elf32::Elf32;
 use super::elf64::Elf64;
 use super::elfxx::ElfXX;
+use super::error::{ElfError, ErrorKind};
 use std::convert::TryInto;
 
 pub type InfoTuple = (&'static str, &'static str, String);
@@ -11,7 +12,6 @@ pub type ReadErr = std::array::TryFromSliceError;
 #[repr(u8)]
 #[derive(Clone, PartialEq)]
 pub enum RangeType {
-    End,
     Ident,
     FileHeader,
     HeaderField(&'static str),
@@ -19,15 +19,32 @@ pub enum RangeType {
     SectionHeader(u32),
     PhdrField(&'static str),
     ShdrField(&'static str),
-    Segment(u16),
-    Section(u16),
+    Segment(u32),
+    Section(u32),
     SegmentSubrange,
+    Symbol,
+    SymbolField(&'static str),
+    Relocation,
+    RelocationField(&'static str),
+    Dynamic(u32),
+    DynamicField(&'static str),
+    VersionRecord(&'static str, usize),
+    VersionField(&'static str),
+    HashHeader,
+    HashField(&'static str),
+    // byte which e_entry points at
+    Entrypoint,
 }
 
-// Interval tree that allows querying point for all intervals that intersect it should be better.
-// We can't beat O(n * m) but the average case should improve.
+// Highlighted areas of the file. Memory is proportional to the number of ranges rather than the
+// file size: starts and ends are kept in separate sorted arrays which are binary searched.
 pub struct Ranges {
-    pub data: Vec<Vec<RangeType>>,
+    file_size: usize,
+    // (start, length, type); ranges with equal starts keep the order they were added in, which
+    // defines their nesting
+    starts: Vec<(usize, usize, RangeType)>,
+    // offsets of last bytes
+    ends: Vec<usize>,
 }
 
 pub struct ParsedIdent {
@@ -42,15 +59,37 @@ pub struct ParsedIdent {
 pub struct ParsedElf<'a> {
     pub filename: String,
     pub file_size: usize,
+    pub machine: u16,
+    pub entry: usize,
+    pub phdr_table: HeaderTable,
+    pub shdr_table: HeaderTable,
     pub information: Vec<(&'static str, &'static str, String)>,
     pub contents: &'a [u8],
     pub ranges: Ranges,
     pub phdrs: Vec<ParsedPhdr>,
     pub shdrs: Vec<ParsedShdr>,
     pub strtab: StrTab<'a>,
-    pub shstrndx: u16,
+    pub shstrndx: usize,
     pub shnstrtab: StrTab<'a>,
     pub notes: Vec<Note>,
+    pub symtabs: Vec<ParsedSymTab>,
+    pub reltabs: Vec<ParsedRelTab>,
+    pub dynamic: Vec<ParsedDyn>,
+    pub verneeds: Vec<ParsedVerneed>,
+    pub verdefs: Vec<ParsedVerdef>,
+    pub hashtabs: Vec<ParsedHashTab>,
+    // indices of sections which each segment contains
+    pub segment_sections: Vec<Vec<usize>>,
+    // non-fatal problems: structures that don't make sense or don't fit into the file
+    pub warnings: Vec<ElfError>,
+}
+
+// Location of the program or section header table, with extended numbering applied
+#[derive(Clone, Copy, Default)]
+pub struct HeaderTable {
+    pub offset: usize,
+    pub entsize: usize,
+    pub num: usize,
 }
 
 pub struct ParsedPhdr {
@@ -76,6 +115,109 @@ pub struct ParsedShdr {
     pub entsize: usize,
 }
 
+pub struct ParsedSym {
+    pub name: String,
+    // offset of the name in the string table, whose bytes may not be valid UTF-8
+    pub name_offset: usize,
+    pub value: usize,
+    pub size: usize,
+    pub bind: u8,
+    pub stype: u8,
+    pub visibility: u8,
+    pub shndx: u16,
+    // real section index from SHT_SYMTAB_SHNDX when shndx is SHN_XINDEX
+    pub ext_shndx: Option<usize>,
+    // entry of .gnu.version and the version string it resolves to, such as "@GLIBC_2.14"
+    pub versym: Option<u16>,
+    pub version: Option<String>,
+}
+
+// Symbols of a single SHT_SYMTAB or SHT_DYNSYM section
+pub struct ParsedSymTab {
+    pub section: usize,
+    pub syms: Vec<ParsedSym>,
+}
+
+pub struct ParsedRel {
+    pub offset: usize,
+    pub sym: u32,
+    pub rtype: u32,
+    pub addend: Option<i64>,
+}
+
+// Entries of a single SHT_REL or SHT_RELA section
+pub struct ParsedRelTab {
+    pub section: usize,
+    pub rels: Vec<ParsedRel>,
+}
+
+pub struct ParsedDyn {
+    pub tag: i64,
+    pub value: u64,
+    // resolved through DT_STRTAB for tags such as DT_NEEDED
+    pub string: Option<String>,
+    // translated from a vaddr for tags such as DT_SYMTAB
+    pub file_offset: Option<usize>,
+}
+
+// Versions required from a single shared object
+pub struct ParsedVerneed {
+    pub offset: usize,
+    pub file: String,
+    pub aux: Vec<ParsedVernaux>,
+}
+
+pub struct ParsedVernaux {
+    pub offset: usize,
+    pub name: String,
+    pub hash: u32,
+    pub flags: u16,
+    pub other: u16,
+}
+
+pub struct ParsedVerdef {
+    pub offset: usize,
+    pub flags: u16,
+    pub ndx: u16,
+    pub hash: u32,
+    pub aux: Vec<ParsedVerdaux>,
+}
+
+pub struct ParsedVerdaux {
+    pub offset: usize,
+    pub name: String,
+}
+
+// A single SHT_HASH or SHT_GNU_HASH section
+pub struct ParsedHashTab {
+    pub section: usize,
+    pub gnu: bool,
+    pub nbuckets: usize,
+    // GNU only: index of the first hashed symbol and the bloom filter parameters
+    pub symoffset: usize,
+    pub bloom_size: usize,
+    pub bloom_shift: u32,
+    // number of symbols in each bucket
+    pub chain_lengths: Vec<usize>,
+    // exported dynamic symbols that a lookup through this table can't find
+    pub unreachable: Vec<String>,
+    pub problems: Vec<String>,
+}
+
+impl ParsedHashTab {
+    // Number of buckets with chains of each length, from 0 to the longest one
+    pub fn chain_length_counts(&self) -> Vec<usize> {
+        let max = self.chain_lengths.iter().copied().max().unwrap_or(0);
+        let mut counts = vec![0; max + 1];
+
+        for &len in &self.chain_lengths {
+            counts[len] += 1;
+        }
+
+        counts
+    }
+}
+
 pub struct Note {
     pub name: Vec<u8>,
     pub desc: Vec<u8>,
@@ -87,40 +229,155 @@ pub struct StrTab<'a> {
     section_size: usize,
 }
 
+// Whether `size` bytes at `start` are within an area. Empty ranges at the very end of an area are
+// outside of it, unless the area is empty itself.
+fn within_area(start: usize, size: usize, area_start: usize, area_size: usize) -> bool {
+    start >= area_start
+        && start - area_start <= area_size.wrapping_sub(1)
+        && (start - area_start)
+            .checked_add(size)
+            .is_some_and(|end| end <= area_size)
+}
+
+// Whether a segment contains a section, by the same rules as readelf's section to segment mapping
+fn section_in_segment(shdr: &ParsedShdr, phdr: &ParsedPhdr) -> bool {
+    let tls = shdr.flags & SHF_TLS != 0;
+    let alloc = shdr.flags & SHF_ALLOC != 0;
+    let nobits = shdr.shtype == SHT_NOBITS;
+
+    // .tbss takes up space only in the TLS template
+    if tls && nobits && phdr.ptype != PT_TLS {
+        return false;
+    }
+
+    // PT_TLS contains only TLS sections, and PT_PHDR contains no sections at all
+    let type_fits = if tls {
+        matches!(phdr.ptype, PT_LOAD | PT_GNU_RELRO | PT_TLS)
+    } else {
+        phdr.ptype != PT_TLS && phdr.ptype != PT_PHDR
+    };
+
+    // segments which are mapped into memory contain only sections which are too
+    let needs_alloc =
+        matches!(phdr.ptype, PT_LOAD | PT_DYNAMIC | PT_GNU_EH_FRAME | PT_GNU_STACK | PT_GNU_RELRO);
+
+    if !type_fits || (needs_alloc && !alloc) {
+        return false;
+    }
+
+    let in_file =
+        nobits || within_area(shdr.file_offset, shdr.size, phdr.file_offset, phdr.file_size);
+    let in_memory = !alloc || within_area(shdr.addr, shdr.size, phdr.vaddr, phdr.memsz);
+
+    if !in_file || !in_memory {
+        return false;
+    }
+
+    // empty sections at the start or the end of PT_DYNAMIC and PT_NOTE aren't in them
+    if (phdr.ptype == PT_DYNAMIC || phdr.ptype == PT_NOTE) && shdr.size == 0 && phdr.memsz != 0 {
+        let inside_file = nobits
+            || (shdr.file_offset > phdr.file_offset
+                && shdr.file_offset - phdr.file_offset < phdr.file_size);
+        let inside_memory =
+            !alloc || (shdr.addr > phdr.vaddr && shdr.addr - phdr.vaddr < phdr.memsz);
+
+        return inside_file && inside_memory;
+    }
+
+    true
+}
+
+// Slice of `size` bytes at `start`, or nothing if it doesn't fit into `buf`
+pub fn file_slice(buf: &[u8], start: usize, size: usize) -> Option<&[u8]> {
+    buf.get(start..start.checked_add(size)?)
+}
+
 impl RangeType {
-    pub fn span_attributes(&self) -> String {
+    // Classes of the span highlighting this range in the hex dump
+    pub fn span_classes(&self) -> String {
         match self {
-            RangeType::Ident => "class='ident'".to_string(),
-            RangeType::FileHeader => "class='ehdr'".to_string(),
-            RangeType::ProgramHeader(idx) => format!("class='bin_phdr{} phdr'", idx),
-            RangeType::SectionHeader(idx) => format!("class='bin_shdr{} shdr'", idx),
-            RangeType::Segment(idx) => format!("class='bin_segment{} segment'", idx),
-            RangeType::Section(idx) => format!("class='bin_section{} section hover'", idx),
-            RangeType::SegmentSubrange => "class='segment_subrange hover'".to_string(),
+            RangeType::Ident => "ident".to_string(),
+            RangeType::FileHeader => "ehdr".to_string(),
+            RangeType::ProgramHeader(idx) => format!("bin_phdr{} phdr", idx),
+            RangeType::SectionHeader(idx) => format!("bin_shdr{} shdr", idx),
+            RangeType::Segment(idx) => format!("bin_segment{} segment", idx),
+            RangeType::Section(idx) => format!("bin_section{} section hover", idx),
+            RangeType::SegmentSubrange => "segment_subrange hover".to_string(),
+            RangeType::Symbol => "sym".to_string(),
+            RangeType::Relocation => "rel".to_string(),
+            RangeType::Dynamic(idx) => format!("dyn dyn{}", idx),
+            RangeType::VersionRecord(kind, offset) => format!("{} ver{:x}", kind, offset),
+            RangeType::HashHeader => "hashhdr".to_string(),
+            RangeType::Entrypoint => "entrypoint".to_string(),
             RangeType::HeaderField(field)
             | RangeType::PhdrField(field)
-            | RangeType::ShdrField(field) => format!("class='{}'", field),
-            RangeType::End => String::new(),
+            | RangeType::ShdrField(field)
+            | RangeType::SymbolField(field)
+            | RangeType::RelocationField(field)
+            | RangeType::DynamicField(field)
+            | RangeType::VersionField(field)
+            | RangeType::HashField(field) => field.to_string(),
         }
     }
 }
 
 impl Ranges {
-    fn new(capacity: usize) -> Ranges {
+    fn new(file_size: usize) -> Ranges {
         Ranges {
-            data: vec![vec![]; capacity],
+            file_size,
+            starts: vec![],
+            ends: vec![],
         }
     }
 
-    pub fn add_range(&mut self, start: usize, end: usize, range_type: RangeType) {
-        self.data[start].push(range_type);
-        self.data[start + end - 1].push(RangeType::End);
+    pub(super) fn add_range(&mut self, start: usize, len: usize, range_type: RangeType) {
+        // callers are expected to validate ranges, but never let a bad one take the report down
+        let last = match start.checked_add(len) {
+            Some(sum) if len != 0 && sum <= self.file_size => sum - 1,
+            _ => return,
+        };
+
+        self.starts.push((start, len, range_type));
+        self.ends.push(last);
+    }
+
+    // Has to be called once all ranges are added, before any lookups
+    fn sort(&mut self) {
+        // stable, so that the insertion order of equal starts is preserved
+        self.starts.sort_by_key(|&(start, _, _)| start);
+        self.ends.sort_unstable();
+    }
+
+    pub fn lookup_range_starts(&self, point: usize) -> impl Iterator<Item = &RangeType> {
+        let first = self.starts.partition_point(|&(start, _, _)| start < point);
+
+        self.starts[first..]
+            .iter()
+            .take_while(move |&&(start, _, _)| start == point)
+            .map(|(_, _, range_type)| range_type)
+    }
+
+    pub fn len(&self) -> usize {
+        self.starts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.starts.is_empty()
+    }
+
+    // All ranges as (start, length, type), ordered the same way as they nest
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &RangeType)> {
+        self.starts
+            .iter()
+            .map(|(start, len, range_type)| (*start, *len, range_type))
     }
 
     pub fn lookup_range_ends(&self, point: usize) -> usize {
-        self.data[point]
+        let first = self.ends.partition_point(|&end| end < point);
+
+        self.ends[first..]
             .iter()
-            .filter(|&x| *x == RangeType::End)
+            .take_while(|&&end| end == point)
             .count()
     }
 }
@@ -138,21 +395,46 @@ impl ParsedIdent {
     }
 }
 
+impl ParsedSym {
+    pub fn section_index(&self) -> usize {
+        self.ext_shndx.unwrap_or(self.shndx as usize)
+    }
+
+    pub fn name_with_version(&self) -> String {
+        match &self.version {
+            Some(version) => format!("{}{}", self.name, version),
+            None => self.name.clone(),
+        }
+    }
+
+    pub fn shndx_string(&self) -> String {
+        match self.ext_shndx {
+            Some(idx) => format!("{} (XINDEX)", idx),
+            None => shndx_to_string(self.shndx),
+        }
+    }
+}
+
 impl ParsedElf<'_> {
-    pub fn from_bytes<'a>(filename: &str, buf: &'a [u8]) -> Result<ParsedElf<'a>, String> {
+    pub fn from_bytes<'a>(filename: &str, buf: &'a [u8]) -> Result<ParsedElf<'a>, ElfError> {
         if buf.len() < ELF_EI_NIDENT as usize {
-            return Err(String::from("file is smaller than ELF header's e_ident"));
+            return Err(ElfError::new(ErrorKind::Truncated, "e_ident", Some(0)));
         }
 
         let ident = ParsedIdent::from_bytes(buf);
 
         if ident.magic != [0x7f, b'E', b'L', b'F'] {
-            return Err(String::from("mismatched magic: not an ELF file"));
+            return Err(ElfError::new(ErrorKind::BadMagic, "e_ident", Some(0))
+                .with_detail("not an ELF file"));
         }
 
         let mut elf = ParsedElf {
             filename: filename.to_string(),
             file_size: buf.len(),
+            machine: 0,
+            entry: 0,
+            phdr_table: HeaderTable::default(),
+            shdr_table: HeaderTable::default(),
             information: vec![],
             contents: buf,
             ranges: Ranges::new(buf.len()),
@@ -162,6 +444,14 @@ impl ParsedElf<'_> {
             shstrndx: 0,
             shnstrtab: StrTab::empty(),
             notes: vec![],
+            symtabs: vec![],
+            reltabs: vec![],
+            dynamic: vec![],
+            verneeds: vec![],
+            verdefs: vec![],
+            hashtabs: vec![],
+            segment_sections: vec![],
+            warnings: vec![],
         };
 
         elf.push_file_info();
@@ -176,10 +466,24 @@ impl ParsedElf<'_> {
 
         elf.add_ident_ranges();
 
+        elf.parse_symtab_shndx(ident.endianness);
+
         elf.parse_string_tables();
 
+        elf.resolve_dynamic();
+
+        elf.parse_versions(ident.endianness);
+
+        elf.parse_hash_tables(ident.class, ident.endianness);
+
         elf.parse_notes(ident.endianness);
 
+        elf.map_sections_to_segments();
+
+        elf.add_entrypoint_range();
+
+        elf.ranges.sort();
+
         Ok(elf)
     }
 
@@ -201,7 +505,7 @@ impl ParsedElf<'_> {
             .push(("file_size", "File size", file_size_str));
     }
 
-    fn push_ident_info(&mut self, ident: &ParsedIdent) -> Result<(), String> {
+    fn push_ident_info(&mut self, ident: &ParsedIdent) -> Result<(), ElfError> {
         let information = &mut self.information;
 
         information.push((
@@ -210,7 +514,14 @@ impl ParsedElf<'_> {
             match ident.class {
                 ELF_CLASS32 => String::from("32-bit"),
                 ELF_CLASS64 => String::from("64-bit"),
-                x => return Err(format!("Unknown bitness: {}", x)),
+                x => {
+                    return Err(ElfError::new(
+                        ErrorKind::UnsupportedClass,
+                        "EI_CLASS",
+                        Some(ELF_EI_CLASS as usize),
+                    )
+                    .with_detail(format!("unknown bitness {}", x)))
+                }
             },
         ));
 
@@ -220,7 +531,14 @@ impl ParsedElf<'_> {
             match ident.endianness {
                 ELF_DATA2LSB => String::from("Little endian"),
                 ELF_DATA2MSB => String::from("Big endian"),
-                x => return Err(format!("Unknown endianness: {}", x)),
+                x => {
+                    return Err(ElfError::new(
+                        ErrorKind::UnsupportedEncoding,
+                        "EI_DATA",
+                        Some(ELF_EI_DATA as usize),
+                    )
+                    .with_detail(format!("unknown endianness {}", x)))
+                }
             },
         ));
 
@@ -268,21 +586,212 @@ impl ParsedElf<'_> {
     }
 
     fn parse_string_tables(&mut self) {
+        let contents = self.contents;
         let shdr = self.shdrs.iter().find(|&shdr| shdr.shtype == SHT_STRTAB);
 
         if let Some(shdr) = shdr {
-            let section = &self.contents[shdr.file_offset..shdr.file_offset + shdr.size];
+            if let Some(section) = file_slice(contents, shdr.file_offset, shdr.size) {
+                self.strtab.populate(section, shdr.size);
+            }
+        }
+
+        let idx = self.shstrndx;
+
+        if idx == SHN_UNDEF as usize {
+            return;
+        }
+
+        match self.shdrs.get(idx) {
+            Some(shdr) => {
+                if let Some(section) = file_slice(contents, shdr.file_offset, shdr.size) {
+                    self.shnstrtab.populate(section, shdr.size);
+                }
+            }
+            None => self.warnings.push(
+                ElfError::new(ErrorKind::OutOfRange, "e_shstrndx", None)
+                    .with_detail(format!("there is no section {}", idx)),
+            ),
+        }
+    }
+
+    fn parse_symtab_shndx(&mut self, endianness: u8) {
+        for idx in 0..self.shdrs.len() {
+            if self.shdrs[idx].shtype != SHT_SYMTAB_SHNDX {
+                continue;
+            }
 
-            self.strtab.populate(section, shdr.size);
+            let link = self.shdrs[idx].link;
+            let section = self.section_bytes(idx);
+
+            let words: Vec<usize> = section
+                .chunks_exact(4)
+                .map(|chunk| {
+                    let bytes = [chunk[0], chunk[1], chunk[2], chunk[3]];
+
+                    if endianness == ELF_DATA2LSB {
+                        u32::from_le_bytes(bytes) as usize
+                    } else {
+                        u32::from_be_bytes(bytes) as usize
+                    }
+                })
+                .collect();
+
+            let symtab = self
+                .symtabs
+                .iter_mut()
+                .find(|symtab| symtab.section == link);
+
+            if let Some(symtab) = symtab {
+                for (sym, word) in symtab.syms.iter_mut().zip(words) {
+                    if sym.shndx == SHN_XINDEX {
+                        sym.ext_shndx = Some(word);
+                    }
+                }
+            }
+        }
+    }
+
+    // Segments which map the file into memory, with their program header indices. Addresses and
+    // offsets are translated through them.
+    pub fn load_segments(&self) -> impl Iterator<Item = (usize, &ParsedPhdr)> {
+        self.phdrs
+            .iter()
+            .enumerate()
+            .filter(|(_, phdr)| phdr.ptype == PT_LOAD)
+    }
+
+    // Offset in file of a virtual address, if a segment maps it to a byte of the file rather than
+    // to memory past the end of its file part, such as .bss
+    pub fn vaddr_to_offset(&self, vaddr: usize) -> Option<usize> {
+        self.load_segments()
+            .map(|(_, phdr)| phdr)
+            .find(|phdr| vaddr >= phdr.vaddr && vaddr - phdr.vaddr < phdr.file_size)
+            .and_then(|phdr| phdr.file_offset.checked_add(vaddr - phdr.vaddr))
+    }
+
+    pub fn offset_to_vaddr(&self, offset: usize) -> Option<usize> {
+        self.load_segments()
+            .map(|(_, phdr)| phdr)
+            .find(|phdr| offset >= phdr.file_offset && offset - phdr.file_offset < phdr.file_size)
+            .and_then(|phdr| phdr.vaddr.checked_add(offset - phdr.file_offset))
+    }
+
+    fn map_sections_to_segments(&mut self) {
+        self.segment_sections = self
+            .phdrs
+            .iter()
+            .map(|phdr| {
+                // the first section header is always null
+                (1..self.shdrs.len())
+                    .filter(|&idx| section_in_segment(&self.shdrs[idx], phdr))
+                    .collect()
+            })
+            .collect();
+    }
+
+    // Indices of segments which contain a section
+    pub fn section_segments(&self, section: usize) -> Vec<usize> {
+        self.segment_sections
+            .iter()
+            .enumerate()
+            .filter(|(_, sections)| sections.contains(&section))
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+    // Files without an entrypoint have e_entry set to zero
+    fn add_entrypoint_range(&mut self) {
+        if self.entry == 0 {
+            return;
+        }
+
+        if let Some(offset) = self.vaddr_to_offset(self.entry) {
+            self.ranges.add_range(offset, 1, RangeType::Entrypoint);
+        }
+    }
+
+    // Contents of a section in the file, or nothing if its header points outside of it
+    pub fn section_bytes(&self, idx: usize) -> &[u8] {
+        let shdr = &self.shdrs[idx];
+
+        file_slice(self.contents, shdr.file_offset, shdr.size).unwrap_or(&[])
+    }
+
+    pub fn section_name(&self, idx: usize) -> &str {
+        match self.shdrs.get(idx) {
+            Some(shdr) => self.shnstrtab.get(shdr.name),
+            None => "",
         }
+    }
 
-        let idx = self.shstrndx as usize;
+    // Name of symbol `idx` of a relocation's symbol table
+    pub fn rel_symbol_name<'a>(&'a self, symtab: Option<&'a ParsedSymTab>, idx: u32) -> &'a str {
+        let sym = match symtab.and_then(|symtab| symtab.syms.get(idx as usize)) {
+            Some(sym) => sym,
+            None => return "",
+        };
+
+        // symbols of section type are nameless and refer to the section itself
+        if sym.stype == STT_SECTION && sym.name.is_empty() {
+            self.section_name(sym.section_index())
+        } else {
+            &sym.name
+        }
+    }
 
-        if self.shstrndx != SHN_UNDEF && idx < self.shdrs.len() {
-            let shdr = &self.shdrs[idx];
-            let section = &self.contents[shdr.file_offset..shdr.file_offset + shdr.size];
+    // Names of dynamic symbols that reference version index `ndx` through .gnu.version
+    pub fn symbols_with_version(&self, ndx: u16) -> Vec<&str> {
+        let mut names = vec![];
 
-            self.shnstrtab.populate(section, shdr.size);
+        for symtab in &self.symtabs {
+            for sym in &symtab.syms {
+                if sym.versym.map(|versym| versym & !VERSYM_HIDDEN) == Some(ndx) {
+                    names.push(sym.name.as_str());
+                }
+            }
+        }
+
+        names
+    }
+
+    fn resolve_dynamic(&mut self) {
+        let find_value = |tag| {
+            self.dynamic
+                .iter()
+                .find(|entry| entry.tag == tag)
+                .map(|entry| entry.value as usize)
+        };
+
+        let strtab_start = find_value(DT_STRTAB).and_then(|vaddr| self.vaddr_to_offset(vaddr));
+        let strtab_size = find_value(DT_STRSZ).unwrap_or(0);
+
+        let strtab =
+            match strtab_start.and_then(|start| file_slice(self.contents, start, strtab_size)) {
+                Some(section) => StrTab::new(section),
+                None => StrTab::empty(),
+            };
+
+        let mut resolved = vec![];
+
+        for entry in &self.dynamic {
+            let string = if dtag_is_string(entry.tag) {
+                Some(strtab.get(entry.value as usize).to_string())
+            } else {
+                None
+            };
+
+            let file_offset = if dtag_is_address(entry.tag) {
+                self.vaddr_to_offset(entry.value as usize)
+            } else {
+                None
+            };
+
+            resolved.push((string, file_offset));
+        }
+
+        for (entry, (string, file_offset)) in self.dynamic.iter_mut().zip(resolved) {
+            entry.string = string;
+            entry.file_offset = file_offset;
         }
     }
 
@@ -303,7 +812,10 @@ impl ParsedElf<'_> {
     // this is pretty ugly in terms of raw addressing, unwieldly offsets, etc.
     // area here stands for segment or section because notes may come from either of them.
     fn parse_note_area(&mut self, area_start: usize, area_size: usize, endianness: u8) {
-        let area = &self.contents[area_start..area_start + area_size];
+        let area = match file_slice(self.contents, area_start, area_size) {
+            Some(area) => area,
+            None => return,
+        };
         let mut start = 0;
 
         loop {
@@ -330,6 +842,10 @@ impl ParsedElf<'_> {
 
 impl Note {
     fn from_bytes(buf: &[u8], endianness: u8) -> Option<(Note, usize)> {
+        if buf.len() < 12 {
+            return None;
+        }
+
         let (namesz, descsz, ntype) = Note::read_header(buf, endianness).ok()?;
         let (namesz, descsz) = (namesz as usize, descsz as usize);
 
@@ -338,7 +854,7 @@ impl Note {
 
         let mut len: usize = 12 + namesz + descsz;
 
-        while len % 4 != 0 {
+        while !len.is_multiple_of(4) {
             len += 1;
         }
 
@@ -371,6 +887,13 @@ impl<'a> StrTab<'a> {
         }
     }
 
+    pub fn new(section: &'a [u8]) -> StrTab<'a> {
+        StrTab {
+            strings: section,
+            section_size: section.len(),
+        }
+    }
+
     // something could be better than references with lifetimes
     fn populate(&mut self, section: &'a [u8], section_size: usize) {
         self.strings = section;
@@ -378,20 +901,224 @@ impl<'a> StrTab<'a> {
     }
 
     pub fn get(&self, idx: usize) -> &str {
-        let start_idx = idx;
+        std::str::from_utf8(self.get_bytes(idx)).unwrap_or("")
+    }
 
-        for end_idx in start_idx..start_idx + self.section_size {
-            if self.strings[end_idx] == 0 {
-                let maybe = std::str::from_utf8(&self.strings[start_idx..end_idx]);
+    // String without its terminating NUL, or nothing if it isn't terminated
+    pub fn get_bytes(&self, idx: usize) -> &[u8] {
+        let strings = &self.strings[..self.section_size.min(self.strings.len())];
 
-                if maybe.is_err() {
-                    return "";
-                }
+        match strings.get(idx..) {
+            Some(rest) => rest
+                .iter()
+                .position(|&c| c == 0)
+                .map_or(&[], |len| &rest[..len]),
+            None => &[],
+        }
+    }
+}
 
-                return maybe.unwrap();
-            }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shdr(shtype: u32, flags: u64, addr: usize, file_offset: usize, size: usize) -> ParsedShdr {
+        ParsedShdr {
+            name: 0,
+            shtype,
+            flags,
+            addr,
+            file_offset,
+            size,
+            link: 0,
+            info: 0,
+            addralign: 1,
+            entsize: 0,
+        }
+    }
+
+    fn phdr(
+        ptype: u32,
+        file_offset: usize,
+        file_size: usize,
+        vaddr: usize,
+        memsz: usize,
+    ) -> ParsedPhdr {
+        ParsedPhdr {
+            ptype,
+            flags: String::new(),
+            file_offset,
+            file_size,
+            vaddr,
+            memsz,
+            alignment: 1,
         }
+    }
+
+    const ALLOC_TLS: u64 = SHF_ALLOC | SHF_TLS;
+
+    // 64-bit little-endian file header with the tables at the given offsets
+    fn ehdr(phoff: u64, phnum: u16, shoff: u64, shnum: u16) -> Vec<u8> {
+        let mut buf = vec![0x7f, b'E', b'L', b'F', 2, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+
+        buf.extend(2u16.to_le_bytes()); // e_type
+        buf.extend(62u16.to_le_bytes()); // e_machine
+        buf.extend(1u32.to_le_bytes()); // e_version
+        buf.extend(0u64.to_le_bytes()); // e_entry
+        buf.extend(phoff.to_le_bytes());
+        buf.extend(shoff.to_le_bytes());
+        buf.extend(0u32.to_le_bytes()); // e_flags
+        buf.extend(64u16.to_le_bytes()); // e_ehsize
+        buf.extend(56u16.to_le_bytes()); // e_phentsize
+        buf.extend(phnum.to_le_bytes());
+        buf.extend(64u16.to_le_bytes()); // e_shentsize
+        buf.extend(shnum.to_le_bytes());
+        buf.extend(0u16.to_le_bytes()); // e_shstrndx
+
+        buf
+    }
+
+    fn push_shdr(buf: &mut Vec<u8>, shtype: u32, offset: u64, size: u64, link: u32, entsize: u64) {
+        buf.extend(0u32.to_le_bytes()); // sh_name
+        buf.extend(shtype.to_le_bytes());
+        buf.extend(0u64.to_le_bytes()); // sh_flags
+        buf.extend(0u64.to_le_bytes()); // sh_addr
+        buf.extend(offset.to_le_bytes());
+        buf.extend(size.to_le_bytes());
+        buf.extend(link.to_le_bytes());
+        buf.extend(0u32.to_le_bytes()); // sh_info
+        buf.extend(1u64.to_le_bytes()); // sh_addralign
+        buf.extend(entsize.to_le_bytes());
+    }
+
+    fn warnings<'a>(elf: &'a ParsedElf) -> Vec<(ErrorKind, &'a str)> {
+        elf.warnings
+            .iter()
+            .map(|w| (w.kind, w.structure.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn truncated_ehdr() {
+        let buf = ehdr(0, 0, 0, 0);
+
+        let err = ParsedElf::from_bytes("a.out", &buf[..40]).err().unwrap();
+        assert_eq!(err.kind, ErrorKind::Truncated);
+
+        let err = ParsedElf::from_bytes("a.out", &buf[..8]).err().unwrap();
+        assert_eq!((err.kind, err.offset), (ErrorKind::Truncated, Some(0)));
+
+        let err = ParsedElf::from_bytes("a.out", b"\x7fELG\x02\x01\x01\0\0\0\0\0\0\0\0\0")
+            .err()
+            .unwrap();
+        assert_eq!(err.kind, ErrorKind::BadMagic);
+    }
+
+    #[test]
+    fn tables_past_eof() {
+        let mut buf = ehdr(64, 2, 0x1000, 1);
+        // only one of the two program headers fits
+        buf.extend([0; 56]);
+
+        let elf = ParsedElf::from_bytes("a.out", &buf).unwrap();
+        assert_eq!(elf.phdrs.len(), 1);
+        assert!(elf.shdrs.is_empty());
+        assert_eq!(
+            warnings(&elf),
+            [
+                (ErrorKind::Truncated, "program header 1"),
+                (ErrorKind::Truncated, "section header 0"),
+            ]
+        );
+        assert_eq!(elf.warnings[0].offset, Some(120));
+        assert_eq!(elf.warnings[1].offset, Some(0x1000));
+    }
+
+    #[test]
+    fn section_size_overflow() {
+        let mut buf = ehdr(0, 0, 64, 2);
+        push_shdr(&mut buf, SHT_NULL, 0, 0, 0, 0);
+        push_shdr(&mut buf, SHT_PROGBITS, 0x10, u64::MAX - 8, 0, 0);
+
+        let elf = ParsedElf::from_bytes("a.out", &buf).unwrap();
+        assert_eq!(elf.shdrs.len(), 2);
+        assert_eq!(warnings(&elf), [(ErrorKind::OutOfRange, "section 1")]);
+        assert_eq!(elf.warnings[0].offset, Some(0x10));
+    }
+
+    #[test]
+    fn symtab_link_out_of_range() {
+        let mut buf = ehdr(0, 0, 64, 2);
+        let symtab = 64 + 2 * 64;
+        push_shdr(&mut buf, SHT_NULL, 0, 0, 0, 0);
+        push_shdr(&mut buf, SHT_SYMTAB, symtab, 2 * 24, 7, 24);
+        buf.extend([0; 2 * 24]);
+
+        let elf = ParsedElf::from_bytes("a.out", &buf).unwrap();
+        assert_eq!(elf.symtabs.len(), 1);
+        assert_eq!(elf.symtabs[0].syms.len(), 2);
+        assert_eq!(warnings(&elf), [(ErrorKind::OutOfRange, "sh_link of section 1")]);
+        assert_eq!(elf.warnings[0].detail, "there is no section 7");
+    }
+
+    #[test]
+    fn areas() {
+        assert!(within_area(0x10, 8, 0x10, 8));
+        assert!(!within_area(0x10, 9, 0x10, 8));
+        assert!(!within_area(0xf, 1, 0x10, 8));
+        // empty ranges are inside only before the end, unless the area is empty too
+        assert!(within_area(0x10, 0, 0x10, 8));
+        assert!(!within_area(0x18, 0, 0x10, 8));
+        assert!(within_area(0x18, 0, 0x18, 0));
+        assert!(!within_area(usize::MAX, 2, 0, usize::MAX));
+    }
+
+    #[test]
+    fn load_segments() {
+        let load = phdr(PT_LOAD, 0x1000, 0x100, 0x401000, 0x200);
+
+        assert!(section_in_segment(&shdr(SHT_PROGBITS, SHF_ALLOC, 0x401000, 0x1000, 0x100), &load));
+        // .bss is past the part in file, and its offset doesn't matter
+        assert!(section_in_segment(&shdr(SHT_NOBITS, SHF_ALLOC, 0x401100, 0x1100, 0x100), &load));
+        assert!(!section_in_segment(&shdr(SHT_NOBITS, SHF_ALLOC, 0x401100, 0x1100, 0x101), &load));
+        // sections which aren't loaded, such as .comment, are not in PT_LOAD
+        assert!(!section_in_segment(&shdr(SHT_PROGBITS, 0, 0, 0x1000, 0x10), &load));
+        // an empty section at the very end
+        assert!(!section_in_segment(&shdr(SHT_PROGBITS, SHF_ALLOC, 0x401200, 0x1100, 0), &load));
+    }
+
+    #[test]
+    fn tls_sections() {
+        let load = phdr(PT_LOAD, 0x1000, 0x100, 0x401000, 0x200);
+        let tls = phdr(PT_TLS, 0x1000, 0x10, 0x401000, 0x20);
+        let tdata = shdr(SHT_PROGBITS, ALLOC_TLS, 0x401000, 0x1000, 0x10);
+        let tbss = shdr(SHT_NOBITS, ALLOC_TLS, 0x401010, 0x1010, 0x10);
+        let data = shdr(SHT_PROGBITS, SHF_ALLOC, 0x401000, 0x1000, 0x10);
+
+        assert!(section_in_segment(&tdata, &load));
+        assert!(section_in_segment(&tdata, &tls));
+        // .tbss takes up space only in the TLS template
+        assert!(!section_in_segment(&tbss, &load));
+        assert!(section_in_segment(&tbss, &tls));
+        assert!(!section_in_segment(&data, &tls));
+    }
+
+    #[test]
+    fn phdr_segment() {
+        let phdrs = phdr(PT_PHDR, 0x40, 0x100, 0x400040, 0x100);
+
+        assert!(!section_in_segment(&shdr(SHT_PROGBITS, SHF_ALLOC, 0x400040, 0x40, 0x10), &phdrs));
+    }
+
+    #[test]
+    fn empty_sections_at_edges() {
+        let note = phdr(PT_NOTE, 0x200, 0x20, 0x400200, 0x20);
+        let dynamic = phdr(PT_DYNAMIC, 0x300, 0x20, 0x400300, 0x20);
 
-        ""
+        assert!(section_in_segment(&shdr(SHT_NOTE, SHF_ALLOC, 0x400200, 0x200, 0x20), &note));
+        assert!(!section_in_segment(&shdr(SHT_NOTE, SHF_ALLOC, 0x400200, 0x200, 0), &note));
+        assert!(section_in_segment(&shdr(SHT_NOTE, SHF_ALLOC, 0x400210, 0x210, 0), &note));
+        assert!(!section_in_segment(&shdr(SHT_DYNAMIC, SHF_ALLOC, 0x400300, 0x300, 0), &dynamic));
+        assert!(!section_in_segment(&shdr(SHT_DYNAMIC, SHF_ALLOC, 0x400320, 0x320, 0), &dynamic));
     }
 }
@@ -0,0 +1,408 @@
+// GNU symbol versioning: .gnu.version (versym), .gnu.version_r (verneed) and
+// .gnu.version_d (verdef). These structures have the same layout in both classes.
+
+use super::defs::*;
+use super::elfxx::ElfHeader;
+use super::parser::*;
+use std::convert::TryInto;
+use std::mem::size_of;
+
+type ElfHalf = u16;
+type ElfWord = u32;
+
+pub struct ElfVerneed {
+    vn_version: ElfHalf,
+    vn_cnt: ElfHalf,
+    vn_file: ElfWord,
+    vn_aux: ElfWord,
+    vn_next: ElfWord,
+}
+
+pub struct ElfVernaux {
+    vna_hash: ElfWord,
+    vna_flags: ElfHalf,
+    vna_other: ElfHalf,
+    vna_name: ElfWord,
+    vna_next: ElfWord,
+}
+
+pub struct ElfVerdef {
+    vd_version: ElfHalf,
+    vd_flags: ElfHalf,
+    vd_ndx: ElfHalf,
+    vd_cnt: ElfHalf,
+    vd_hash: ElfWord,
+    vd_aux: ElfWord,
+    vd_next: ElfWord,
+}
+
+pub struct ElfVerdaux {
+    vda_name: ElfWord,
+    vda_next: ElfWord,
+}
+
+#[rustfmt::skip]
+impl ElfHeader for ElfVerneed {
+    fn describe() -> String {
+        String::from("version needed entry")
+    }
+    fn from_le_bytes(buf: &[u8]) -> Result<ElfVerneed, ReadErr> {
+        Ok(ElfVerneed {
+            vn_version: ElfHalf::from_le_bytes(buf[ 0.. 2].try_into()?),
+            vn_cnt:     ElfHalf::from_le_bytes(buf[ 2.. 4].try_into()?),
+            vn_file:    ElfWord::from_le_bytes(buf[ 4.. 8].try_into()?),
+            vn_aux:     ElfWord::from_le_bytes(buf[ 8..12].try_into()?),
+            vn_next:    ElfWord::from_le_bytes(buf[12..16].try_into()?),
+        })
+    }
+    fn from_be_bytes(buf: &[u8]) -> Result<ElfVerneed, ReadErr> {
+        Ok(ElfVerneed {
+            vn_version: ElfHalf::from_be_bytes(buf[ 0.. 2].try_into()?),
+            vn_cnt:     ElfHalf::from_be_bytes(buf[ 2.. 4].try_into()?),
+            vn_file:    ElfWord::from_be_bytes(buf[ 4.. 8].try_into()?),
+            vn_aux:     ElfWord::from_be_bytes(buf[ 8..12].try_into()?),
+            vn_next:    ElfWord::from_be_bytes(buf[12..16].try_into()?),
+        })
+    }
+}
+
+#[rustfmt::skip]
+impl ElfHeader for ElfVernaux {
+    fn describe() -> String {
+        String::from("version needed auxiliary entry")
+    }
+    fn from_le_bytes(buf: &[u8]) -> Result<ElfVernaux, ReadErr> {
+        Ok(ElfVernaux {
+            vna_hash:  ElfWord::from_le_bytes(buf[ 0.. 4].try_into()?),
+            vna_flags: ElfHalf::from_le_bytes(buf[ 4.. 6].try_into()?),
+            vna_other: ElfHalf::from_le_bytes(buf[ 6.. 8].try_into()?),
+            vna_name:  ElfWord::from_le_bytes(buf[ 8..12].try_into()?),
+            vna_next:  ElfWord::from_le_bytes(buf[12..16].try_into()?),
+        })
+    }
+    fn from_be_bytes(buf: &[u8]) -> Result<ElfVernaux, ReadErr> {
+        Ok(ElfVernaux {
+            vna_hash:  ElfWord::from_be_bytes(buf[ 0.. 4].try_into()?),
+            vna_flags: ElfHalf::from_be_bytes(buf[ 4.. 6].try_into()?),
+            vna_other: ElfHalf::from_be_bytes(buf[ 6.. 8].try_into()?),
+            vna_name:  ElfWord::from_be_bytes(buf[ 8..12].try_into()?),
+            vna_next:  ElfWord::from_be_bytes(buf[12..16].try_into()?),
+        })
+    }
+}
+
+#[rustfmt::skip]
+impl ElfHeader for ElfVerdef {
+    fn describe() -> String {
+        String::from("version definition entry")
+    }
+    fn from_le_bytes(buf: &[u8]) -> Result<ElfVerdef, ReadErr> {
+        Ok(ElfVerdef {
+            vd_version: ElfHalf::from_le_bytes(buf[ 0.. 2].try_into()?),
+            vd_flags:   ElfHalf::from_le_bytes(buf[ 2.. 4].try_into()?),
+            vd_ndx:     ElfHalf::from_le_bytes(buf[ 4.. 6].try_into()?),
+            vd_cnt:     ElfHalf::from_le_bytes(buf[ 6.. 8].try_into()?),
+            vd_hash:    ElfWord::from_le_bytes(buf[ 8..12].try_into()?),
+            vd_aux:     ElfWord::from_le_bytes(buf[12..16].try_into()?),
+            vd_next:    ElfWord::from_le_bytes(buf[16..20].try_into()?),
+        })
+    }
+    fn from_be_bytes(buf: &[u8]) -> Result<ElfVerdef, ReadErr> {
+        Ok(ElfVerdef {
+            vd_version: ElfHalf::from_be_bytes(buf[ 0.. 2].try_into()?),
+            vd_flags:   ElfHalf::from_be_bytes(buf[ 2.. 4].try_into()?),
+            vd_ndx:     ElfHalf::from_be_bytes(buf[ 4.. 6].try_into()?),
+            vd_cnt:     ElfHalf::from_be_bytes(buf[ 6.. 8].try_into()?),
+            vd_hash:    ElfWord::from_be_bytes(buf[ 8..12].try_into()?),
+            vd_aux:     ElfWord::from_be_bytes(buf[12..16].try_into()?),
+            vd_next:    ElfWord::from_be_bytes(buf[16..20].try_into()?),
+        })
+    }
+}
+
+#[rustfmt::skip]
+impl ElfHeader for ElfVerdaux {
+    fn describe() -> String {
+        String::from("version definition auxiliary entry")
+    }
+    fn from_le_bytes(buf: &[u8]) -> Result<ElfVerdaux, ReadErr> {
+        Ok(ElfVerdaux {
+            vda_name: ElfWord::from_le_bytes(buf[0..4].try_into()?),
+            vda_next: ElfWord::from_le_bytes(buf[4..8].try_into()?),
+        })
+    }
+    fn from_be_bytes(buf: &[u8]) -> Result<ElfVerdaux, ReadErr> {
+        Ok(ElfVerdaux {
+            vda_name: ElfWord::from_be_bytes(buf[0..4].try_into()?),
+            vda_next: ElfWord::from_be_bytes(buf[4..8].try_into()?),
+        })
+    }
+}
+
+// Reads a structure at `offset` within `section`, failing softly if it doesn't fit
+fn read_at<T: ElfHeader>(section: &[u8], offset: usize, endianness: u8) -> Option<T> {
+    let bytes = section.get(offset..offset.checked_add(size_of::<T>())?)?;
+
+    T::from_bytes(bytes, endianness).ok()
+}
+
+#[rustfmt::skip]
+fn add_verneed_ranges(start: usize, ranges: &mut Ranges) {
+    ranges.add_range(start,      2, RangeType::VersionField("vn_version"));
+    ranges.add_range(start +  2, 2, RangeType::VersionField("vn_cnt"));
+    ranges.add_range(start +  4, 4, RangeType::VersionField("vn_file"));
+    ranges.add_range(start +  8, 4, RangeType::VersionField("vn_aux"));
+    ranges.add_range(start + 12, 4, RangeType::VersionField("vn_next"));
+}
+
+#[rustfmt::skip]
+fn add_vernaux_ranges(start: usize, ranges: &mut Ranges) {
+    ranges.add_range(start,      4, RangeType::VersionField("vna_hash"));
+    ranges.add_range(start +  4, 2, RangeType::VersionField("vna_flags"));
+    ranges.add_range(start +  6, 2, RangeType::VersionField("vna_other"));
+    ranges.add_range(start +  8, 4, RangeType::VersionField("vna_name"));
+    ranges.add_range(start + 12, 4, RangeType::VersionField("vna_next"));
+}
+
+#[rustfmt::skip]
+fn add_verdef_ranges(start: usize, ranges: &mut Ranges) {
+    ranges.add_range(start,      2, RangeType::VersionField("vd_version"));
+    ranges.add_range(start +  2, 2, RangeType::VersionField("vd_flags"));
+    ranges.add_range(start +  4, 2, RangeType::VersionField("vd_ndx"));
+    ranges.add_range(start +  6, 2, RangeType::VersionField("vd_cnt"));
+    ranges.add_range(start +  8, 4, RangeType::VersionField("vd_hash"));
+    ranges.add_range(start + 12, 4, RangeType::VersionField("vd_aux"));
+    ranges.add_range(start + 16, 4, RangeType::VersionField("vd_next"));
+}
+
+#[rustfmt::skip]
+fn add_verdaux_ranges(start: usize, ranges: &mut Ranges) {
+    ranges.add_range(start,     4, RangeType::VersionField("vda_name"));
+    ranges.add_range(start + 4, 4, RangeType::VersionField("vda_next"));
+}
+
+impl ParsedElf<'_> {
+    pub(super) fn parse_versions(&mut self, endianness: u8) {
+        for idx in 0..self.shdrs.len() {
+            match self.shdrs[idx].shtype {
+                SHT_VER_NEED => self.parse_verneed(idx, endianness),
+                SHT_VER_DEF => self.parse_verdef(idx, endianness),
+                _ => {}
+            }
+        }
+
+        for idx in 0..self.shdrs.len() {
+            if self.shdrs[idx].shtype == SHT_VER_SYM {
+                self.parse_versym(idx, endianness);
+            }
+        }
+    }
+
+    fn linked_strtab(&self, idx: usize) -> StrTab<'_> {
+        match self.shdrs.get(self.shdrs[idx].link) {
+            Some(_) => StrTab::new(self.section_bytes(self.shdrs[idx].link)),
+            None => StrTab::new(&[]),
+        }
+    }
+
+    fn parse_verneed(&mut self, idx: usize, endianness: u8) {
+        let section = self.section_bytes(idx);
+        let strtab = self.linked_strtab(idx);
+        let base = self.shdrs[idx].file_offset;
+        let mut needs = vec![];
+        let mut ranges = vec![];
+        let mut offset = 0;
+
+        // sh_info holds the number of entries, which also protects against cycles
+        for _ in 0..self.shdrs[idx].info {
+            let verneed = match read_at::<ElfVerneed>(section, offset, endianness) {
+                Some(verneed) if verneed.vn_version == VER_NEED_CURRENT => verneed,
+                _ => break,
+            };
+
+            ranges.push((base + offset, size_of::<ElfVerneed>(), "verneed"));
+
+            let mut aux = vec![];
+            let mut aux_offset = offset + verneed.vn_aux as usize;
+
+            for _ in 0..verneed.vn_cnt {
+                let vernaux = match read_at::<ElfVernaux>(section, aux_offset, endianness) {
+                    Some(vernaux) => vernaux,
+                    None => break,
+                };
+
+                ranges.push((base + aux_offset, size_of::<ElfVernaux>(), "vernaux"));
+
+                aux.push(ParsedVernaux {
+                    offset: base + aux_offset,
+                    name: strtab.get(vernaux.vna_name as usize).to_string(),
+                    hash: vernaux.vna_hash,
+                    flags: vernaux.vna_flags,
+                    other: vernaux.vna_other,
+                });
+
+                if vernaux.vna_next == 0 {
+                    break;
+                }
+
+                aux_offset += vernaux.vna_next as usize;
+            }
+
+            needs.push(ParsedVerneed {
+                offset: base + offset,
+                file: strtab.get(verneed.vn_file as usize).to_string(),
+                aux,
+            });
+
+            if verneed.vn_next == 0 {
+                break;
+            }
+
+            offset += verneed.vn_next as usize;
+        }
+
+        for (start, len, kind) in ranges {
+            self.ranges
+                .add_range(start, len, RangeType::VersionRecord(kind, start));
+
+            if kind == "verneed" {
+                add_verneed_ranges(start, &mut self.ranges);
+            } else {
+                add_vernaux_ranges(start, &mut self.ranges);
+            }
+        }
+
+        self.verneeds.extend(needs);
+    }
+
+    fn parse_verdef(&mut self, idx: usize, endianness: u8) {
+        let section = self.section_bytes(idx);
+        let strtab = self.linked_strtab(idx);
+        let base = self.shdrs[idx].file_offset;
+        let mut defs = vec![];
+        let mut ranges = vec![];
+        let mut offset = 0;
+
+        for _ in 0..self.shdrs[idx].info {
+            let verdef = match read_at::<ElfVerdef>(section, offset, endianness) {
+                Some(verdef) if verdef.vd_version == VER_DEF_CURRENT => verdef,
+                _ => break,
+            };
+
+            ranges.push((base + offset, size_of::<ElfVerdef>(), "verdef"));
+
+            let mut aux = vec![];
+            let mut aux_offset = offset + verdef.vd_aux as usize;
+
+            for _ in 0..verdef.vd_cnt {
+                let verdaux = match read_at::<ElfVerdaux>(section, aux_offset, endianness) {
+                    Some(verdaux) => verdaux,
+                    None => break,
+                };
+
+                ranges.push((base + aux_offset, size_of::<ElfVerdaux>(), "verdaux"));
+
+                aux.push(ParsedVerdaux {
+                    offset: base + aux_offset,
+                    name: strtab.get(verdaux.vda_name as usize).to_string(),
+                });
+
+                if verdaux.vda_next == 0 {
+                    break;
+                }
+
+                aux_offset += verdaux.vda_next as usize;
+            }
+
+            defs.push(ParsedVerdef {
+                offset: base + offset,
+                flags: verdef.vd_flags,
+                ndx: verdef.vd_ndx,
+                hash: verdef.vd_hash,
+                aux,
+            });
+
+            if verdef.vd_next == 0 {
+                break;
+            }
+
+            offset += verdef.vd_next as usize;
+        }
+
+        for (start, len, kind) in ranges {
+            self.ranges
+                .add_range(start, len, RangeType::VersionRecord(kind, start));
+
+            if kind == "verdef" {
+                add_verdef_ranges(start, &mut self.ranges);
+            } else {
+                add_verdaux_ranges(start, &mut self.ranges);
+            }
+        }
+
+        self.verdefs.extend(defs);
+    }
+
+    // Name of a version index as used in .gnu.version, and whether it is a definition
+    fn version_name(&self, ndx: u16) -> Option<(&str, bool)> {
+        for verdef in &self.verdefs {
+            // the first auxiliary entry of a definition names the version itself
+            if verdef.ndx == ndx && verdef.flags & VER_FLG_BASE == 0 {
+                return verdef.aux.first().map(|aux| (aux.name.as_str(), true));
+            }
+        }
+
+        for verneed in &self.verneeds {
+            for vernaux in &verneed.aux {
+                if vernaux.other == ndx {
+                    return Some((vernaux.name.as_str(), false));
+                }
+            }
+        }
+
+        None
+    }
+
+    fn parse_versym(&mut self, idx: usize, endianness: u8) {
+        let shdr = &self.shdrs[idx];
+        let (base, link) = (shdr.file_offset, shdr.link);
+        let section = self.section_bytes(idx);
+        let mut versions = vec![];
+
+        for chunk in section.chunks_exact(2) {
+            let versym = if endianness == ELF_DATA2LSB {
+                u16::from_le_bytes([chunk[0], chunk[1]])
+            } else {
+                u16::from_be_bytes([chunk[0], chunk[1]])
+            };
+
+            let hidden = versym & VERSYM_HIDDEN != 0;
+            let ndx = versym & !VERSYM_HIDDEN;
+
+            let version = match self.version_name(ndx) {
+                Some((name, true)) if !hidden => Some(format!("@@{}", name)),
+                Some((name, _)) => Some(format!("@{}", name)),
+                None => None,
+            };
+
+            versions.push((versym, version));
+        }
+
+        for i in 0..versions.len() {
+            self.ranges
+                .add_range(base + i * 2, 2, RangeType::VersionField("versym"));
+        }
+
+        let symtab = self
+            .symtabs
+            .iter_mut()
+            .find(|symtab| symtab.section == link);
+
+        if let Some(symtab) = symtab {
+            for (sym, (versym, version)) in symtab.syms.iter_mut().zip(versions) {
+                sym.versym = Some(versym);
+                sym.version = version;
+            }
+        }
+    }
+}
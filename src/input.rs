@@ -0,0 +1,50 @@
+// Contents of an input file. A mapped file is paged in by the OS only where it is actually looked
+// at, so huge core dumps and binaries with lots of debug info don't have to fit into memory.
+
+use std::fs::File;
+use std::io::{self, Read};
+use std::ops::Deref;
+
+pub enum Input {
+    #[cfg(feature = "mmap")]
+    Mapped(memmap2::Mmap),
+    Buffered(Vec<u8>),
+}
+
+impl Input {
+    pub fn open(path: &str) -> io::Result<Input> {
+        let mut file = File::open(path)?;
+
+        #[cfg(feature = "mmap")]
+        {
+            // SAFETY: the mapping is read-only and private. The file being truncated by someone
+            // else while it's mapped is a hazard which is accepted, as with any tool mapping files.
+            // Empty and special files can't be mapped, those are read instead.
+            if let Ok(map) = unsafe { memmap2::Mmap::map(&file) } {
+                return Ok(Input::Mapped(map));
+            }
+        }
+
+        Input::read(&mut file)
+    }
+
+    pub fn read<R: Read>(reader: &mut R) -> io::Result<Input> {
+        let mut contents = vec![];
+
+        reader.read_to_end(&mut contents)?;
+
+        Ok(Input::Buffered(contents))
+    }
+}
+
+impl Deref for Input {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            #[cfg(feature = "mmap")]
+            Input::Mapped(map) => map,
+            Input::Buffered(contents) => contents,
+        }
+    }
+}
@@ -1,5 +1,7 @@
 const arrows = document.getElementById('arrows');
 let connections = [];
+// range which clicking on a range jumps to
+let jumpTargets = new Map();
 let batchElems = '';
 
 function getAbsPosition(elem) {
@@ -18,22 +20,35 @@ function getAbsPosition(elem) {
     };
 }
 
-function getBoundingBoxSizes(elem) {
-    let rect = elem.getBoundingClientRect();
+// Position of a byte in the page. Ranges are connected by offsets rather than by elements,
+// because most of the dump is not rendered at any given time.
+function getOffsetPosition(offset) {
+    let dump = getAbsPosition(bytesElem);
 
     return {
-        w: rect.width || elem.offsetWidth,
-        h: rect.height || elem.offsetHeight
+        x: dump.x + bytesElem.clientLeft + offsetColumn(offset) * 3 * charWidth,
+        y: dump.y + bytesElem.clientTop + offsetRow(offset) * lineHeight
     };
 }
 
-function addSvgArrow(elem1, elem2) {
-    let off1 = getAbsPosition(elem1);
-    let off2 = getAbsPosition(elem2);
+// position of the first byte of a range
+function getRangePosition(idx) {
+    return getOffsetPosition(rangeStarts[idx]);
+}
+
+// Width of the part of a range on its first row
+function getRangeWidth(idx) {
+    let bytes = Math.min(rangeLasts[idx] - rangeStarts[idx] + 1,
+                         columns - offsetColumn(rangeStarts[idx]));
+
+    return (bytes * 3 - 1) * charWidth;
+}
 
-    let bb1 = getBoundingBoxSizes(elem1);
+function addSvgArrow(range1, range2) {
+    let off1 = getRangePosition(range1);
+    let off2 = getRangePosition(range2);
 
-    let x1 = off1.x + bb1.w / 2;
+    let x1 = off1.x + getRangeWidth(range1) / 2;
     let y1 = off1.y;
 
     let x2 = off2.x;
@@ -43,30 +58,48 @@ function addSvgArrow(elem1, elem2) {
                       + 'x2="' + x2 + '" y2="' + y2 + '"/>';
 }
 
-function jumpToElem(elem) {
-    elem.scrollIntoView();
-}
-
-function setJumpCallback(elemFrom, elemTo) {
-    elemFrom.addEventListener("click", function(e) {
-        jumpToElem(elemTo);
-    }, true);
+function jumpToRange(idx) {
+    window.scrollTo(window.scrollX, getRangePosition(idx).y);
 }
 
 function connect(sel1, sel2) {
-    let elem1 = document.querySelector(sel1);
-    let elem2 = document.querySelector(sel2);
+    let range1 = findRange(sel1);
+    let range2 = findRange(sel2);
 
-    if (elem1 === null || elem2 === null) {
+    if (range1 === null || range2 === null) {
         return;
     }
 
-    connections.push([elem1, elem2]);
+    connections.push([range1, range2]);
 
-    addSvgArrow(elem1, elem2);
+    addSvgArrow(range1, range2);
 
-    setJumpCallback(elem1, elem2);
-    setJumpCallback(elem2, elem1);
+    jumpTargets.set(range1, range2);
+    jumpTargets.set(range2, range1);
+}
+
+// Range which clicking on an element in a column of the dump jumps to. The innermost connected
+// range under the cursor wins.
+function connectedTarget(elem, column) {
+    for (; elem !== column; elem = elem.parentNode) {
+        let target = jumpTargets.get(Number(elem.dataset.range));
+
+        if (target !== undefined) {
+            return target;
+        }
+    }
+
+    return null;
+}
+
+for (let column of [bytesElem, asciiElem]) {
+    column.addEventListener("click", function(e) {
+        let target = connectedTarget(e.target, column);
+
+        if (target !== null) {
+            jumpToRange(target);
+        }
+    }, false);
 }
 
 function clearArrows() {
@@ -94,4 +127,3 @@ function redrawArrows() {
 window.onresize = function() {
     redrawArrows();
 }
-
@@ -0,0 +1,56 @@
+// Translates a number both as a virtual address and as an offset, through PT_LOAD segments. The
+// results link to the places in the dump.
+const converterInput = document.getElementById('converter_input');
+const converterResult = document.getElementById('converter_result');
+
+function placeLink(offset) {
+    let hex = '0x' + offset.toString(16);
+
+    return "<a href='#off=" + hex + "'>" + hex + '</a>';
+}
+
+function describeAddress(vaddr) {
+    let hex = '0x' + vaddr.toString(16);
+    let translated = translateVaddr(vaddr);
+
+    if (translated === null) {
+        return 'Address ' + hex + ' is not in a PT_LOAD segment';
+    }
+
+    if (translated.offset === null) {
+        return 'Address ' + hex + ' is in segment ' + translated.segment
+               + ', past its part in the file';
+    }
+
+    return 'Address ' + hex + ' is at offset ' + placeLink(translated.offset)
+           + ' in segment ' + translated.segment;
+}
+
+function describeOffset(offset) {
+    let hex = '0x' + offset.toString(16);
+
+    if (offset >= BigInt(fileLen)) {
+        return 'Offset ' + hex + ' is beyond the end of the file';
+    }
+
+    let translated = translateOffset(Number(offset));
+
+    if (translated === null) {
+        return 'Offset ' + placeLink(offset) + ' is not in a PT_LOAD segment';
+    }
+
+    return 'Offset ' + placeLink(offset) + ' is at address 0x' + translated.vaddr.toString(16)
+           + ' in segment ' + translated.segment;
+}
+
+converterInput.addEventListener('input', function () {
+    let number = parseNumber(converterInput.value);
+
+    if (converterInput.value.trim() === '') {
+        converterResult.innerHTML = '';
+    } else if (number === null) {
+        converterResult.textContent = 'Expected a number such as 0x401040 or 4198464';
+    } else {
+        converterResult.innerHTML = describeAddress(number) + '<br>' + describeOffset(number);
+    }
+});
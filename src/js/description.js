@@ -44,6 +44,55 @@ let descriptions = {
     sh_addralign: "Address alignment of the section (sh_addralign)",
     sh_entsize:   "Size of each entry if section has table of fixed-size entries (sh_entsize)",
     section:      "Section",
+    sym:          "Symbol table entry",
+    st_name:      "Offset to the linked string table containing this symbol name (st_name)",
+    st_value:     "Symbol value, usually a virtual address (st_value)",
+    st_size:      "Size of the object this symbol refers to (st_size)",
+    st_info:      "Symbol type and binding (st_info)",
+    st_other:     "Symbol visibility (st_other)",
+    st_shndx:     "Index of the section this symbol is defined in (st_shndx)",
+    rel:          "Relocation entry",
+    r_offset:     "Location to apply the relocation to (r_offset)",
+    r_info:       "Symbol table index and relocation type (r_info)",
+    r_addend:     "Constant addend used to compute the relocated value (r_addend)",
+    dyn:          "Dynamic section entry",
+    d_tag:        "Type of the entry (d_tag)",
+    d_val:        "Value or address, interpreted depending on the tag (d_un)",
+    verneed:      "Version needed entry (Verneed)",
+    vn_version:   "Version of this structure, should be 1 (vn_version)",
+    vn_cnt:       "Number of associated auxiliary entries (vn_cnt)",
+    vn_file:      "Offset to the file name string in the linked string table (vn_file)",
+    vn_aux:       "Offset in bytes to the first auxiliary entry (vn_aux)",
+    vn_next:      "Offset in bytes to the next version needed entry (vn_next)",
+    vernaux:      "Version needed auxiliary entry (Vernaux)",
+    vna_hash:     "Hash value of the version name (vna_hash)",
+    vna_flags:    "Version flags (vna_flags)",
+    vna_other:    "Version index used in the version symbol table (vna_other)",
+    vna_name:     "Offset to the version name string in the linked string table (vna_name)",
+    vna_next:     "Offset in bytes to the next auxiliary entry (vna_next)",
+    verdef:       "Version definition entry (Verdef)",
+    vd_version:   "Version of this structure, should be 1 (vd_version)",
+    vd_flags:     "Version flags (vd_flags)",
+    vd_ndx:       "Version index used in the version symbol table (vd_ndx)",
+    vd_cnt:       "Number of associated auxiliary entries (vd_cnt)",
+    vd_hash:      "Hash value of the version name (vd_hash)",
+    vd_aux:       "Offset in bytes to the first auxiliary entry (vd_aux)",
+    vd_next:      "Offset in bytes to the next version definition entry (vd_next)",
+    verdaux:      "Version definition auxiliary entry (Verdaux)",
+    vda_name:     "Offset to the version name string in the linked string table (vda_name)",
+    vda_next:     "Offset in bytes to the next auxiliary entry (vda_next)",
+    versym:       "Version index of the corresponding dynamic symbol",
+    hashhdr:      "Hash table header",
+    nbucket:      "Number of buckets (nbucket)",
+    nchain:       "Number of chain entries, equal to the number of symbols (nchain)",
+    nbuckets:     "Number of buckets (nbuckets)",
+    symoffset:    "Index of the first symbol accessible through the hash table (symoffset)",
+    bloom_size:   "Number of words in the bloom filter (bloom_size)",
+    bloom_shift:  "Shift applied to the hash for the second bloom filter bit (bloom_shift)",
+    bloom:        "Bloom filter word",
+    bucket:       "Hash bucket: index of the first symbol in the chain",
+    chain:        "Hash chain entry",
+    entrypoint:   "Entrypoint, where e_entry points",
     section_in_segment: "Section in segment",
 }
 let separator = "<br>&#x2193<br>";
@@ -0,0 +1,599 @@
+const offsetsElem = document.getElementById('offsets');
+const bytesElem = document.getElementById('bytes');
+const asciiElem = document.getElementById('ascii');
+// ranges are looked up in blocks of this many bytes, see openRanges()
+const checkpointSize = 4096;
+
+// Data blocks are base64 in lines of 76 characters and a newline, which atob ignores
+function decodeData(id, length) {
+    let elem = document.getElementById(id);
+    let text = elem.textContent;
+    let data = new Uint8Array(length);
+    let chunkSize = 77 * 1024;
+    let pos = 0;
+
+    for (let i = 0; i < text.length; i += chunkSize) {
+        let chunk = atob(text.substr(i, chunkSize));
+
+        for (let j = 0; j < chunk.length; ++j) {
+            data[pos++] = chunk.charCodeAt(j);
+        }
+    }
+
+    elem.remove();
+
+    return data;
+}
+
+// parts of the file included in the report, stored one after another in fileBytes
+const regions = dumpRegions.map(([start, end]) => ({ start: start, end: end, dataStart: 0 }));
+
+for (let i = 1; i < regions.length; ++i) {
+    regions[i].dataStart = regions[i - 1].dataStart + regions[i - 1].end - regions[i - 1].start;
+}
+
+const dataLen = regions.reduce((len, region) => len + region.end - region.start, 0);
+const fileBytes = decodeData('file_data', dataLen);
+const rangeClasses = document.getElementById('range_classes').textContent.split('\n');
+// offsets beyond 4 GiB need 64-bit floats, which are exact up to 2^53
+const rangeStarts = new Float64Array(rangeCount);
+// offsets of last bytes
+const rangeLasts = new Float64Array(rangeCount);
+const rangeClassIdx = new Uint32Array(rangeCount);
+
+(function () {
+    let recordSize = 20;
+    let words = new DataView(decodeData('range_data', rangeCount * recordSize).buffer);
+    let getUint64 = pos => words.getUint32(pos, true) + words.getUint32(pos + 4, true) * 2 ** 32;
+
+    for (let i = 0; i < rangeCount; ++i) {
+        rangeStarts[i] = getUint64(i * recordSize);
+        rangeLasts[i] = rangeStarts[i] + getUint64(i * recordSize + 8) - 1;
+        rangeClassIdx[i] = words.getUint32(i * recordSize + 16, true);
+    }
+})();
+
+// for finding how many ranges end at an offset
+const sortedLasts = rangeLasts.slice().sort();
+const checkpointOffsets = [];
+const checkpoints = computeCheckpoints();
+
+// The dump consists of blocks of rows: regions of the file, and a single row in place of each
+// part of the file which is left out
+let blocks = [];
+let rowCount = 0;
+
+let offsetsWindow = null;
+let bytesWindow = null;
+let asciiWindow = null;
+let charWidth = 0;
+let lineHeight = 0;
+let renderedFirst = 0;
+let renderedLast = 0;
+// bytes marked in the dump, such as search results, as sorted ranges which don't overlap. Ends are
+// exclusive.
+let markStarts = [];
+let markEnds = [];
+let currentMark = -1;
+// show virtual addresses of rows in PT_LOAD segments instead of their offsets
+let showAddresses = false;
+
+// index of the last element of a list sorted by key which is not greater than value
+function lastAtMost(list, key, value) {
+    let lo = 0, hi = list.length;
+
+    while (lo < hi) {
+        let mid = (lo + hi) >>> 1;
+
+        if (list[mid][key] <= value) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+
+    return lo - 1;
+}
+
+// index of the first element of a sorted array which is not less than value
+function lowerBound(array, value) {
+    let lo = 0, hi = array.length;
+
+    while (lo < hi) {
+        let mid = (lo + hi) >>> 1;
+
+        if (array[mid] < value) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+
+    return lo;
+}
+
+function regionOfData(pos) {
+    return regions[lastAtMost(regions, 'dataStart', pos)];
+}
+
+function dataPosition(offset) {
+    let region = regions[lastAtMost(regions, 'start', offset)];
+
+    return region.dataStart + offset - region.start;
+}
+
+// Ranges which contain the byte at every checkpointSize bytes of data but start before it
+function computeCheckpoints() {
+    let result = [];
+    let active = [];
+    let next = 0;
+
+    for (let pos = 0; pos < dataLen; pos += checkpointSize) {
+        let region = regionOfData(pos);
+        let offset = region.start + pos - region.dataStart;
+
+        while (next < rangeCount && rangeStarts[next] < offset) {
+            active.push(next++);
+        }
+
+        active = active.filter(idx => rangeLasts[idx] >= offset);
+
+        checkpointOffsets.push(offset);
+        result.push(active.slice());
+    }
+
+    return result;
+}
+
+// ranges which contain given offset but start before it, in the order they nest
+function openRanges(offset) {
+    let checkpoint = Math.floor(dataPosition(offset) / checkpointSize);
+    let from = checkpointOffsets[checkpoint];
+    let open = checkpoints[checkpoint].filter(idx => rangeLasts[idx] >= offset);
+
+    for (let i = lowerBound(rangeStarts, from); rangeStarts[i] < offset; ++i) {
+        if (rangeLasts[i] >= offset) {
+            open.push(i);
+        }
+    }
+
+    return open;
+}
+
+function layoutBlocks() {
+    let end = 0;
+
+    blocks = [];
+    rowCount = 0;
+
+    let addBlock = function (start, end, dataStart, rows) {
+        blocks.push({ start: start, end: end, dataStart: dataStart, firstRow: rowCount, rows: rows });
+        rowCount += rows;
+    };
+
+    for (let region of regions) {
+        if (region.start > end) {
+            addBlock(end, region.start, -1, 1);
+        }
+
+        addBlock(region.start, region.end, region.dataStart,
+                 Math.ceil((region.end - region.start) / columns));
+
+        end = region.end;
+    }
+
+    if (end < fileLen) {
+        addBlock(end, fileLen, -1, 1);
+    }
+}
+
+function isSkipped(block) {
+    return block.dataStart < 0;
+}
+
+// Row and column of an offset in the dump. Offsets which are left out are placed at the start of
+// the row standing for them.
+function offsetRow(offset) {
+    let block = blocks[lastAtMost(blocks, 'start', offset)];
+
+    return block.firstRow + (isSkipped(block) ? 0 : Math.floor((offset - block.start) / columns));
+}
+
+function offsetColumn(offset) {
+    let block = blocks[lastAtMost(blocks, 'start', offset)];
+
+    return isSkipped(block) ? 0 : (offset - block.start) % columns;
+}
+
+function isIncluded(offset) {
+    return !isSkipped(blocks[lastAtMost(blocks, 'start', offset)]);
+}
+
+// Offset in file of a virtual address, found through the PT_LOAD segment which maps it. Addresses
+// are BigInts, as 64-bit ones don't fit into numbers. The offset is null for addresses beyond the
+// file size of the segment, such as those of .bss.
+function translateVaddr(vaddr) {
+    for (let segment of loadSegments) {
+        let delta = vaddr - segment.vaddr;
+
+        if (delta >= 0n && delta < segment.memSize) {
+            return {
+                segment: segment.index,
+                offset: delta < segment.fileSize ? segment.offset + Number(delta) : null
+            };
+        }
+    }
+
+    return null;
+}
+
+// Virtual address of a byte of the file, as a BigInt, and the segment which maps it
+function translateOffset(offset) {
+    for (let segment of loadSegments) {
+        let delta = BigInt(offset - segment.offset);
+
+        if (delta >= 0n && delta < segment.fileSize) {
+            return { segment: segment.index, vaddr: segment.vaddr + delta };
+        }
+    }
+
+    return null;
+}
+
+// offset of the first byte of a row
+function rowOffset(row) {
+    let block = blocks[lastAtMost(blocks, 'firstRow', row)];
+
+    return block.start + (isSkipped(block) ? 0 : (row - block.firstRow) * columns);
+}
+
+function hasClass(idx, cls) {
+    return (' ' + rangeClasses[rangeClassIdx[idx]] + ' ').includes(' ' + cls + ' ');
+}
+
+let firstRanges = null;
+
+function firstRangeWithClass(cls) {
+    if (firstRanges === null) {
+        let seen = new Uint8Array(rangeClasses.length);
+
+        firstRanges = new Map();
+
+        for (let i = 0; i < rangeCount; ++i) {
+            if (seen[rangeClassIdx[i]]) {
+                continue;
+            }
+
+            seen[rangeClassIdx[i]] = 1;
+
+            for (let name of rangeClasses[rangeClassIdx[i]].split(' ')) {
+                if (!firstRanges.has(name)) {
+                    firstRanges.set(name, i);
+                }
+            }
+        }
+    }
+
+    let idx = firstRanges.get(cls);
+
+    return idx === undefined ? null : idx;
+}
+
+// Range matching a selector like '.bin_phdr0' or '.bin_phdr0 > .p_offset', the same one which
+// document.querySelector() would find if the whole dump was rendered
+function findRange(selector) {
+    let classes = selector.split('>').map(part => part.trim().substring(1));
+    let found = firstRangeWithClass(classes[0]);
+
+    for (let k = 1; k < classes.length && found !== null; ++k) {
+        let parent = found;
+
+        found = null;
+
+        for (let i = parent + 1; i < rangeCount && rangeStarts[i] <= rangeLasts[parent]; ++i) {
+            if (rangeLasts[i] <= rangeLasts[parent] && hasClass(i, classes[k])) {
+                found = i;
+                break;
+            }
+        }
+    }
+
+    return found;
+}
+
+function isGraphic(byte) {
+    return byte > 0x20 && byte < 0x7f;
+}
+
+function escapeChar(ch) {
+    switch (ch) {
+        case '&': return '&amp;';
+        case '<': return '&lt;';
+        case '>': return '&gt;';
+        case '"': return '&quot;';
+        case '\'': return '&apos;';
+        default: return ch;
+    }
+}
+
+function formatByte(offset, byte) {
+    // magic
+    if (offset < 4 && isGraphic(byte)) {
+        return '&nbsp;' + escapeChar(String.fromCharCode(byte));
+    }
+
+    return (byte < 0x10 ? '0' : '') + byte.toString(16);
+}
+
+function formatChar(offset, byte) {
+    return isGraphic(byte) ? escapeChar(String.fromCharCode(byte)) : '.';
+}
+
+function spanOpening(idx) {
+    return "<span class='" + rangeClasses[rangeClassIdx[idx]] + "' data-range='" + idx + "'>";
+}
+
+// Spans are closed in the order ranges end. Ranges overlap without nesting at times, such as
+// segments which start at the same offset, in which case the ranges above the one which ends are
+// closed and opened again. Both the bytes and the ASCII column have the same spans.
+function renderRanges(block, start, end, format, separator) {
+    let stack = openRanges(start);
+    let html = stack.map(spanOpening).join('');
+    let nextStart = lowerBound(rangeStarts, start);
+    let nextLast = lowerBound(sortedLasts, start);
+    let mark = lowerBound(markEnds, start + 1);
+
+    for (let offset = start; offset < end; ++offset) {
+        for (; nextStart < rangeCount && rangeStarts[nextStart] === offset; ++nextStart) {
+            html += spanOpening(nextStart);
+            stack.push(nextStart);
+        }
+
+        let cell = format(offset, fileBytes[block.dataStart + offset - block.start]);
+
+        while (mark < markEnds.length && markEnds[mark] <= offset) {
+            ++mark;
+        }
+
+        if (mark < markEnds.length && markStarts[mark] <= offset) {
+            let cls = mark === currentMark ? 'mark current_mark' : 'mark';
+
+            cell = "<span class='" + cls + "'>" + cell + '</span>';
+        }
+
+        html += cell;
+
+        let ending = 0;
+
+        for (; nextLast < rangeCount && sortedLasts[nextLast] === offset; ++nextLast) {
+            ++ending;
+        }
+
+        let reopen = [];
+
+        while (ending > 0) {
+            let idx = stack.pop();
+
+            html += '</span>';
+
+            if (rangeLasts[idx] === offset) {
+                --ending;
+            } else {
+                reopen.push(idx);
+            }
+        }
+
+        for (let i = reopen.length - 1; i >= 0; --i) {
+            html += spanOpening(reopen[i]);
+            stack.push(reopen[i]);
+        }
+
+        if (offset !== end - 1) {
+            html += (offset + 1 - block.start) % columns === 0 ? '\n' : separator;
+        }
+    }
+
+    return html + '</span>'.repeat(stack.length);
+}
+
+function renderBytes(block, start, end) {
+    return renderRanges(block, start, end, formatByte, ' ');
+}
+
+function renderAscii(block, start, end) {
+    return renderRanges(block, start, end, formatChar, '');
+}
+
+// Rows outside of PT_LOAD segments keep their offsets when addresses are shown
+function formatRowOffset(offset) {
+    let translated = showAddresses ? translateOffset(offset) : null;
+
+    if (showAddresses && translated === null) {
+        return "<span class='file_offset'>" + offset.toString(16) + '</span>';
+    }
+
+    return (translated === null ? offset : translated.vaddr).toString(16);
+}
+
+function renderOffsets(block, firstRow, lastRow) {
+    let offsets = [];
+
+    for (let row = firstRow; row < lastRow; ++row) {
+        offsets.push(formatRowOffset(block.start + row * columns));
+    }
+
+    return offsets.join('\n');
+}
+
+// Rows of all three columns, in rows relative to the start of the whole dump
+function renderRows(firstRow, lastRow) {
+    let offsets = [], bytes = [], ascii = [];
+
+    for (let i = lastAtMost(blocks, 'firstRow', firstRow); i < blocks.length; ++i) {
+        let block = blocks[i];
+
+        if (block.firstRow >= lastRow) {
+            break;
+        }
+
+        if (isSkipped(block)) {
+            offsets.push('*');
+            bytes.push("<span class='skipped'>(0x" + (block.end - block.start).toString(16)
+                       + " bytes left out)</span>");
+            ascii.push('');
+            continue;
+        }
+
+        let from = Math.max(firstRow, block.firstRow) - block.firstRow;
+        let to = Math.min(lastRow, block.firstRow + block.rows) - block.firstRow;
+        let start = block.start + from * columns;
+        let end = Math.min(block.end, block.start + to * columns);
+
+        offsets.push(renderOffsets(block, from, to));
+        bytes.push(renderBytes(block, start, end));
+        ascii.push(renderAscii(block, start, end));
+    }
+
+    return {
+        offsets: offsets.join('\n'),
+        bytes: bytes.join('\n'),
+        ascii: ascii.join('\n')
+    };
+}
+
+function createWindow(column) {
+    let win = document.createElement('div');
+
+    win.className = 'dump_window';
+
+    column.appendChild(win);
+
+    return win;
+}
+
+function sizeColumns() {
+    let offsetDigits = Math.max(1, (fileLen - 1).toString(16).length);
+
+    if (showAddresses) {
+        for (let segment of loadSegments) {
+            let last = segment.vaddr + segment.fileSize - 1n;
+
+            offsetDigits = Math.max(offsetDigits, last.toString(16).length);
+        }
+    }
+
+    offsetsElem.style.width = offsetDigits + 'ch';
+    // 2 chars per byte + 1 space, without the final space
+    bytesElem.style.width = columns * 3 - 1 + 'ch';
+    asciiElem.style.width = columns + 'ch';
+
+    for (let column of [offsetsElem, bytesElem, asciiElem]) {
+        column.style.height = rowCount * lineHeight + 'px';
+    }
+}
+
+function measureFont() {
+    let probe = document.createElement('div');
+    let lines = 100;
+
+    probe.style.position = 'absolute';
+    probe.style.visibility = 'hidden';
+    probe.textContent = ('0'.repeat(lines) + '\n').repeat(lines - 1) + '0'.repeat(lines);
+
+    bytesElem.appendChild(probe);
+
+    let rect = probe.getBoundingClientRect();
+
+    charWidth = rect.width / lines;
+    lineHeight = rect.height / lines;
+
+    probe.remove();
+}
+
+// Renders the visible rows along with a screen worth of rows before and after them, so that
+// scrolling a bit doesn't need rendering
+function renderVisibleRows(force = false) {
+    let top = bytesElem.getBoundingClientRect().top + bytesElem.clientTop;
+    let visibleRows = Math.ceil(window.innerHeight / lineHeight);
+    let first = Math.min(rowCount, Math.max(0, Math.floor(-top / lineHeight)));
+    let last = Math.min(rowCount, first + visibleRows + 1);
+
+    if (!force && first >= renderedFirst && last <= renderedLast) {
+        return;
+    }
+
+    renderedFirst = Math.max(0, first - visibleRows);
+    renderedLast = Math.min(rowCount, last + visibleRows);
+
+    let rows = renderRows(renderedFirst, renderedLast);
+
+    for (let win of [offsetsWindow, bytesWindow, asciiWindow]) {
+        win.style.top = renderedFirst * lineHeight + 'px';
+    }
+
+    offsetsWindow.innerHTML = rows.offsets;
+    bytesWindow.innerHTML = rows.bytes;
+    asciiWindow.innerHTML = rows.ascii;
+}
+
+function setupDump() {
+    layoutBlocks();
+    measureFont();
+    sizeColumns();
+
+    offsetsWindow = createWindow(offsetsElem);
+    bytesWindow = createWindow(bytesElem);
+    asciiWindow = createWindow(asciiElem);
+
+    renderVisibleRows(true);
+}
+
+function setShowAddresses(show) {
+    showAddresses = show;
+
+    sizeColumns();
+    renderVisibleRows(true);
+}
+
+function setMarks(starts, ends, current) {
+    markStarts = starts;
+    markEnds = ends;
+    currentMark = current;
+
+    renderVisibleRows(true);
+}
+
+// Re-flows the dump into rows of given number of bytes, keeping the row at the top of the window
+// in view
+function setColumns(count) {
+    let top = bytesElem.getBoundingClientRect().top + bytesElem.clientTop;
+    let topRow = Math.min(rowCount - 1, Math.max(0, Math.floor(-top / lineHeight)));
+    let offset = rowOffset(topRow);
+    // position of the dump in the page, as the window may scroll when the dump gets shorter
+    let dumpTop = window.scrollY + top;
+
+    columns = count;
+
+    layoutBlocks();
+    sizeColumns();
+
+    if (topRow > 0) {
+        window.scrollTo(window.scrollX, dumpTop + offsetRow(offset) * lineHeight);
+    }
+
+    renderVisibleRows(true);
+}
+
+setupDump();
+
+window.addEventListener('scroll', function () {
+    renderVisibleRows();
+});
+
+window.addEventListener('resize', function () {
+    // the font size changes when zooming
+    measureFont();
+    sizeColumns();
+
+    renderVisibleRows(true);
+});
@@ -1,29 +1,66 @@
-let color = "#ee9";
-
-function addPairHighlighting(elem, depElem) {
-    elem.addEventListener("mouseenter", function(event) {
-        event.target.style.backgroundColor = color;
-        depElem.style.backgroundColor = color;
-    }, false);
-
-    elem.addEventListener("mouseleave", function(event) {
-        event.target.style.backgroundColor = "";
-        depElem.style.backgroundColor = "";
-    }, false);
-}
+let color = "var(--hover)";
+// pairs of classes whose first elements are highlighted together
+let highlightPairs = new Map();
+let highlighted = [];
 
 function highlightClasses(primaryClass, secondaryClass) {
-    let primaryElems = document.getElementsByClassName(primaryClass);
-    let secondaryElems = document.getElementsByClassName(secondaryClass);
+    highlightPairs.set(primaryClass, secondaryClass);
+    highlightPairs.set(secondaryClass, primaryClass);
+}
+
+// Elements are looked up on every event, as the hex dump is rendered anew on scroll
+function findPairedElems(target) {
+    for (let [cls, depCls] of highlightPairs) {
+        let elem = target.closest('.' + cls);
+        let depElem = document.getElementsByClassName(depCls)[0];
+
+        if (elem !== null && depElem !== undefined) {
+            return [elem, depElem];
+        }
+    }
+
+    return [];
+}
+
+document.addEventListener("mouseover", function(event) {
+    highlighted = findPairedElems(event.target);
+
+    for (let elem of highlighted) {
+        elem.style.backgroundColor = color;
+    }
+}, false);
 
-    if (primaryElems.length === 0 || secondaryElems.length === 0) {
+document.addEventListener("mouseout", function(event) {
+    for (let elem of highlighted) {
+        elem.style.backgroundColor = "";
+    }
+
+    highlighted = [];
+}, false);
+
+// the range under the cursor is outlined in the other column of the dump
+let counterparts = [];
+
+document.addEventListener("mouseover", function(event) {
+    let elem = event.target.closest('[data-range]');
+
+    if (elem === null) {
         return;
     }
 
-    let primaryElem = primaryElems[0];
-    let secondaryElem = secondaryElems[0];
+    let other = bytesElem.contains(elem) ? asciiElem : bytesElem;
 
-    addPairHighlighting(primaryElem, secondaryElem);
-    addPairHighlighting(secondaryElem, primaryElem);
-}
+    counterparts = other.querySelectorAll("[data-range='" + elem.dataset.range + "']");
+
+    for (let counterpart of counterparts) {
+        counterpart.classList.add('counterpart');
+    }
+}, false);
+
+document.addEventListener("mouseout", function(event) {
+    for (let counterpart of counterparts) {
+        counterpart.classList.remove('counterpart');
+    }
 
+    counterparts = [];
+}, false);
@@ -0,0 +1,165 @@
+// Places in the report can be linked to with fragments such as #off=0x1f40, #vaddr=0x401000,
+// #section=.text or #segment=2. Clicking on the dump puts a link to the clicked structure into
+// the address bar.
+const flashDuration = 1500;
+
+// Range which a section or segment is shown as, or its header if it has no contents in file
+function areaRange(kind, idx) {
+    let range = findRange('.bin_' + kind + idx);
+
+    if (range === null) {
+        range = findRange(kind === 'section' ? '.bin_shdr' + idx : '.bin_phdr' + idx);
+    }
+
+    return range;
+}
+
+// The smallest range containing an offset, which is the innermost one
+function innermostRange(offset) {
+    let found = null;
+    let candidates = openRanges(offset);
+
+    for (let i = lowerBound(rangeStarts, offset); i < rangeCount && rangeStarts[i] === offset; ++i) {
+        candidates.push(i);
+    }
+
+    let length = idx => rangeLasts[idx] - rangeStarts[idx];
+
+    for (let idx of candidates) {
+        if (found === null || length(idx) <= length(found)) {
+            found = idx;
+        }
+    }
+
+    return found;
+}
+
+// Offset and range to show for a fragment, or null if it doesn't point anywhere
+function fragmentTarget(hash) {
+    let [key, value] = hash.replace(/^#/, '').split('=', 2);
+
+    if (value === undefined) {
+        return null;
+    }
+
+    value = decodeURIComponent(value);
+
+    if (key === 'section' || key === 'segment') {
+        let idx = key === 'section' ? sectionNames.indexOf(value) : -1;
+
+        if (idx < 0 && /^[0-9]+$/.test(value)) {
+            idx = Number(value);
+        }
+
+        let range = idx < 0 ? null : areaRange(key, idx);
+
+        return range === null ? null : { offset: rangeStarts[range], range: range };
+    }
+
+    let number = parseNumber(value);
+    let offset = null;
+
+    if (number === null) {
+        return null;
+    }
+
+    if (key === 'off') {
+        offset = number < BigInt(fileLen) ? Number(number) : null;
+    } else if (key === 'vaddr') {
+        let translated = translateVaddr(number);
+
+        offset = translated === null ? null : translated.offset;
+    }
+
+    return offset === null ? null : { offset: offset, range: innermostRange(offset) };
+}
+
+function flashRange(idx) {
+    for (let elem of document.querySelectorAll("[data-range='" + idx + "']")) {
+        // restarts the animation if it is already running
+        elem.classList.remove('flash');
+        void elem.offsetWidth;
+        elem.classList.add('flash');
+
+        setTimeout(() => elem.classList.remove('flash'), flashDuration);
+    }
+}
+
+function followFragment() {
+    let target = fragmentTarget(location.hash);
+
+    if (target === null) {
+        return;
+    }
+
+    window.scrollTo(window.scrollX, getOffsetPosition(target.offset).y - window.innerHeight / 3);
+
+    // rows are rendered on scroll events, which come after this
+    renderVisibleRows();
+
+    if (target.range !== null) {
+        flashRange(target.range);
+    }
+}
+
+function linkToRange(idx) {
+    for (let cls of rangeClasses[rangeClassIdx[idx]].split(' ')) {
+        let match = cls.match(/^bin_(section|segment)([0-9]+)$/);
+
+        if (match === null) {
+            continue;
+        }
+
+        let num = Number(match[2]);
+        let name = sectionNames[num];
+
+        // names are used only when they are unambiguous
+        if (match[1] === 'section' && name !== '' && sectionNames.indexOf(name) === num
+            && sectionNames.lastIndexOf(name) === num) {
+            return '#section=' + encodeURIComponent(name);
+        }
+
+        return '#' + match[1] + '=' + num;
+    }
+
+    return '#off=0x' + rangeStarts[idx].toString(16);
+}
+
+// The address bar is updated without scrolling or adding to the history
+function setFragment(hash) {
+    try {
+        history.replaceState(null, '', hash);
+    } catch (e) {
+        // some browsers don't allow this for files opened from disk
+    }
+}
+
+// Clicks which jump along an arrow link to where they jump
+for (let column of [bytesElem, asciiElem]) {
+    column.addEventListener('click', function (e) {
+        let target = connectedTarget(e.target, column);
+
+        if (target === null) {
+            let elem = e.target.closest('[data-range]');
+
+            target = elem !== null && column.contains(elem) ? Number(elem.dataset.range) : null;
+        }
+
+        if (target !== null) {
+            setFragment(linkToRange(target));
+        }
+    }, false);
+}
+
+offsetsElem.addEventListener('click', function (e) {
+    let top = offsetsElem.getBoundingClientRect().top + offsetsElem.clientTop;
+    let row = Math.floor((e.clientY - top) / lineHeight);
+
+    if (row >= 0 && row < rowCount) {
+        setFragment('#off=0x' + rowOffset(row).toString(16));
+    }
+}, false);
+
+window.addEventListener('hashchange', followFragment);
+// after the page is laid out, and after browsers restore the scroll position on reload
+window.addEventListener('load', followFragment);
@@ -0,0 +1,283 @@
+const searchPanel = document.getElementById('search');
+const searchMode = document.getElementById('search_mode');
+const searchInput = document.getElementById('search_input');
+const searchStatus = document.getElementById('search_status');
+// there's no point in going through more matches one by one
+const maxMatches = 10000;
+const searchPlaceholders = {
+    hex: '7f 45 ?? 46',
+    text: 'Text',
+    utf16: 'Text',
+    offset: '0x1040 or 4160',
+    vaddr: '0x401040'
+};
+
+let matchStarts = [];
+let matchEnds = [];
+let currentMatch = -1;
+// mode and query which the matches are for
+let searched = null;
+
+// Pairs of hex digits, with ?? for any byte
+function parseHexPattern(query) {
+    let digits = query.replace(/\s+/g, '');
+    let pattern = [];
+
+    if (digits.length % 2 !== 0) {
+        return null;
+    }
+
+    for (let i = 0; i < digits.length; i += 2) {
+        let pair = digits.substring(i, i + 2);
+
+        if (pair === '??') {
+            pattern.push(-1);
+        } else if (/^[0-9a-fA-F]{2}$/.test(pair)) {
+            pattern.push(parseInt(pair, 16));
+        } else {
+            return null;
+        }
+    }
+
+    return pattern.some(byte => byte >= 0) ? pattern : null;
+}
+
+// UTF-16 in the byte order of the file
+function encodeUtf16(text) {
+    let pattern = [];
+
+    for (let i = 0; i < text.length; ++i) {
+        let unit = text.charCodeAt(i);
+
+        if (bigEndian) {
+            pattern.push(unit >> 8, unit & 0xff);
+        } else {
+            pattern.push(unit & 0xff, unit >> 8);
+        }
+    }
+
+    return pattern;
+}
+
+// Numbers are decimal or hexadecimal with a 0x prefix, as on the command line
+function parseNumber(query) {
+    let text = query.trim();
+
+    return /^(0x[0-9a-f]+|[0-9]+)$/i.test(text) ? BigInt(text) : null;
+}
+
+function matchesAt(pattern, pos) {
+    for (let i = 0; i < pattern.length; ++i) {
+        if (pattern[i] >= 0 && fileBytes[pos + i] !== pattern[i]) {
+            return false;
+        }
+    }
+
+    return true;
+}
+
+// Matches don't overlap and don't cross the parts of the file which are left out
+function findPattern(pattern) {
+    // the first byte which isn't a wildcard is looked for with indexOf()
+    let anchor = pattern.findIndex(byte => byte >= 0);
+
+    for (let region of regions) {
+        let end = region.dataStart + region.end - region.start;
+        let pos = region.dataStart + anchor;
+
+        while (matchStarts.length < maxMatches) {
+            pos = fileBytes.indexOf(pattern[anchor], pos);
+
+            if (pos < 0 || pos - anchor + pattern.length > end) {
+                break;
+            }
+
+            let start = pos - anchor;
+
+            if (matchesAt(pattern, start)) {
+                matchStarts.push(region.start + start - region.dataStart);
+                matchEnds.push(region.start + start - region.dataStart + pattern.length);
+                pos = start + pattern.length + anchor;
+            } else {
+                ++pos;
+            }
+        }
+    }
+}
+
+// Finds matches and returns a message if there are none
+function findMatches(mode, query) {
+    if (mode === 'hex' || mode === 'text' || mode === 'utf16') {
+        let pattern;
+
+        if (mode === 'hex') {
+            pattern = parseHexPattern(query);
+        } else if (mode === 'text') {
+            pattern = query === '' ? null : Array.from(new TextEncoder().encode(query));
+        } else {
+            pattern = query === '' ? null : encodeUtf16(query);
+        }
+
+        if (pattern === null) {
+            return mode === 'hex' ? 'Expected pairs of hex digits, ?? for any byte' : '';
+        }
+
+        findPattern(pattern);
+
+        return 'No matches';
+    }
+
+    let number = parseNumber(query);
+
+    if (number === null) {
+        return 'Expected a number such as 0x1040';
+    }
+
+    let offset = number;
+
+    if (mode === 'vaddr') {
+        let translated = translateVaddr(number);
+
+        if (translated === null) {
+            return 'No PT_LOAD segment maps this address';
+        }
+
+        if (translated.offset === null) {
+            return 'Address is not in the file part of segment ' + translated.segment;
+        }
+
+        offset = BigInt(translated.offset);
+    }
+
+    if (offset >= BigInt(fileLen)) {
+        return 'Offset is beyond the end of the file';
+    }
+
+    offset = Number(offset);
+
+    matchStarts.push(offset);
+    matchEnds.push(offset + 1);
+
+    return '';
+}
+
+function updateSearchStatus(message) {
+    if (matchStarts.length === 0) {
+        searchStatus.textContent = message;
+        return;
+    }
+
+    let count = matchStarts.length + (matchStarts.length === maxMatches ? '+' : '');
+    let status = (currentMatch + 1) + ' of ' + count;
+
+    if (!isIncluded(matchStarts[currentMatch])) {
+        status += ', left out of the dump';
+    }
+
+    searchStatus.textContent = status;
+}
+
+function showMatch(idx) {
+    currentMatch = idx;
+
+    setMarks(matchStarts, matchEnds, currentMatch);
+
+    let y = getOffsetPosition(matchStarts[idx]).y;
+
+    if (y < window.scrollY || y + lineHeight > window.scrollY + window.innerHeight) {
+        window.scrollTo(window.scrollX, y - window.innerHeight / 3);
+    }
+
+    updateSearchStatus('');
+}
+
+function search() {
+    let mode = searchMode.value;
+    let query = searchInput.value;
+
+    matchStarts = [];
+    matchEnds = [];
+    currentMatch = -1;
+    searched = { mode: mode, query: query };
+
+    let message = findMatches(mode, query);
+
+    if (matchStarts.length === 0) {
+        setMarks([], [], -1);
+        updateSearchStatus(message);
+        return;
+    }
+
+    showMatch(0);
+}
+
+function stepMatch(step) {
+    if (searched === null || searched.mode !== searchMode.value
+        || searched.query !== searchInput.value) {
+        search();
+        return;
+    }
+
+    if (matchStarts.length > 0) {
+        showMatch((currentMatch + step + matchStarts.length) % matchStarts.length);
+    }
+}
+
+function openSearch() {
+    searchPanel.style.display = 'block';
+    searchInput.focus();
+    searchInput.select();
+}
+
+function closeSearch() {
+    searchPanel.style.display = 'none';
+    searched = null;
+
+    setMarks([], [], -1);
+}
+
+document.getElementById('search_toggle').onclick = function() {
+    if (searchPanel.style.display === 'block') {
+        closeSearch();
+    } else {
+        openSearch();
+    }
+}
+
+document.getElementById('search_next').onclick = function() {
+    stepMatch(1);
+}
+
+document.getElementById('search_prev').onclick = function() {
+    stepMatch(-1);
+}
+
+document.getElementById('search_close').onclick = closeSearch;
+
+searchInput.addEventListener('keydown', function (e) {
+    if (e.key === 'Enter') {
+        stepMatch(e.shiftKey ? -1 : 1);
+    } else if (e.key === 'Escape') {
+        closeSearch();
+    }
+});
+
+searchMode.addEventListener('change', function () {
+    searchInput.placeholder = searchPlaceholders[searchMode.value];
+
+    if (searchInput.value !== '') {
+        search();
+    }
+});
+
+// "/" opens the search unless something else is being typed into
+document.addEventListener('keydown', function (e) {
+    let typing = ['INPUT', 'SELECT', 'TEXTAREA'].includes(document.activeElement.tagName);
+
+    if (e.key === '/' && !typing && !e.ctrlKey && !e.metaKey && !e.altKey) {
+        e.preventDefault();
+        openSearch();
+    }
+});
+
+searchInput.placeholder = searchPlaceholders[searchMode.value];
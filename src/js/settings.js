@@ -1,6 +1,12 @@
 const settings = document.getElementById('settings');
 const help = document.getElementById('help');
+const converter = document.getElementById('converter');
+// there's no mapping of sections to segments in files without either
+const mapping = document.getElementById('mapping');
 const arrow_input = document.getElementById('arrow_opacity_range');
+const columns_input = document.getElementById('columns_input');
+const theme_select = document.getElementById('theme_select');
+const addresses_checkbox = document.getElementById('addresses_checkbox');
 /* const arrows is reused from arrows.js */
 
 function toggleVisibility(elem) {
@@ -11,16 +17,33 @@ function toggleVisibility(elem) {
     }
 }
 
+// only one of the panels of the right menu is shown at a time
+function togglePanel(panel) {
+    for (let other of [settings, converter, mapping, help]) {
+        if (other !== panel && other !== null) {
+            other.style.display = "none";
+        }
+    }
+
+    toggleVisibility(panel);
+}
+
 document.getElementById('settings_toggle').onclick = function() {
-    toggleVisibility(settings);
+    togglePanel(settings);
+}
 
-    help.style.display = "none";
+document.getElementById('converter_toggle').onclick = function() {
+    togglePanel(converter);
 }
 
-document.getElementById('help_toggle').onclick = function() {
-    toggleVisibility(help);
+if (mapping !== null) {
+    document.getElementById('mapping_toggle').onclick = function() {
+        togglePanel(mapping);
+    }
+}
 
-    settings.style.display = "none";
+document.getElementById('help_toggle').onclick = function() {
+    togglePanel(help);
 }
 
 function setArrowOpacity(e) {
@@ -29,3 +52,32 @@ function setArrowOpacity(e) {
 
 arrow_input.addEventListener('change', setArrowOpacity);
 arrow_input.addEventListener('input', setArrowOpacity);
+
+function setColumnCount(e) {
+    let count = e.target.valueAsNumber;
+
+    // min, max and whole numbers are checked by the input
+    if (isNaN(count) || !e.target.checkValidity()) {
+        return;
+    }
+
+    if (count !== columns) {
+        setColumns(count);
+        redrawArrows();
+    }
+}
+
+columns_input.addEventListener('change', setColumnCount);
+
+theme_select.value = storedTheme();
+
+theme_select.addEventListener('change', function (e) {
+    storeTheme(e.target.value);
+    applyTheme(e.target.value);
+});
+
+// the column gets wider, which moves the dump and the arrows
+addresses_checkbox.addEventListener('change', function (e) {
+    setShowAddresses(e.target.checked);
+    redrawArrows();
+});
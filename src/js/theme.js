@@ -0,0 +1,37 @@
+// The theme is set before the page is shown, so that it doesn't flash white. Theme "auto" follows
+// the preference of the system.
+const darkQuery = window.matchMedia('(prefers-color-scheme: dark)');
+const themeKey = 'elfcat_theme';
+
+// localStorage may be unavailable for files opened from disk
+function storedTheme() {
+    try {
+        return localStorage.getItem(themeKey) || 'auto';
+    } catch (e) {
+        return 'auto';
+    }
+}
+
+function storeTheme(theme) {
+    try {
+        if (theme === 'auto') {
+            localStorage.removeItem(themeKey);
+        } else {
+            localStorage.setItem(themeKey, theme);
+        }
+    } catch (e) {
+        // the theme is only applied to this page then
+    }
+}
+
+function applyTheme(theme) {
+    let dark = theme === 'dark' || (theme === 'auto' && darkQuery.matches);
+
+    document.documentElement.dataset.theme = dark ? 'dark' : 'light';
+}
+
+applyTheme(storedTheme());
+
+darkQuery.addEventListener('change', function () {
+    applyTheme(storedTheme());
+});
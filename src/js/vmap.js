@@ -0,0 +1,37 @@
+// The memory map is shown next to the dump. Hovering a mapping or a section in it outlines where it
+// is in the dump, and clicking it follows its link.
+const vmap = document.getElementById('vmap');
+let vmapOutlined = [];
+
+document.getElementById('vmap_toggle').onclick = function() {
+    vmap.style.display = vmap.style.display === 'inline-block' ? 'none' : 'inline-block';
+}
+
+vmap.addEventListener('mouseover', function (e) {
+    let elem = e.target.closest('[class^=bin_]');
+
+    if (elem === null) {
+        return;
+    }
+
+    let match = elem.classList[0].match(/^bin_(section|segment)([0-9]+)$/);
+    let range = match === null ? null : areaRange(match[1], Number(match[2]));
+
+    if (range === null) {
+        return;
+    }
+
+    vmapOutlined = document.querySelectorAll("[data-range='" + range + "']");
+
+    for (let elem of vmapOutlined) {
+        elem.classList.add('counterpart');
+    }
+}, false);
+
+vmap.addEventListener('mouseout', function () {
+    for (let elem of vmapOutlined) {
+        elem.classList.remove('counterpart');
+    }
+
+    vmapOutlined = [];
+}, false);
@@ -0,0 +1,531 @@
+use crate::elf::defs::*;
+use crate::elf::error::ErrorKind;
+use crate::elf::parser::{
+    Note, ParsedDyn, ParsedElf, ParsedHashTab, ParsedPhdr, ParsedRelTab, ParsedShdr, ParsedSymTab,
+    ParsedVerdef, ParsedVerneed, RangeType,
+};
+use std::io::{self, Write};
+
+// Bumped on every change which could break consumers: removed or renamed members, or changed
+// meaning of values. Added members don't bump it. See json_schema.md.
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+
+const INDENT: &str = "  ";
+
+// Streaming writer of indented JSON, so that files with millions of symbols and ranges don't have
+// to be turned into a tree in memory first
+struct JsonWriter<'a> {
+    o: &'a mut dyn Write,
+    // for each open object or array, whether it already has members
+    nonempty: Vec<bool>,
+    // the next value follows a key on the same line
+    after_key: bool,
+}
+
+trait ToJson {
+    fn write_json(&self, w: &mut JsonWriter) -> io::Result<()>;
+}
+
+impl JsonWriter<'_> {
+    fn new(o: &mut dyn Write) -> JsonWriter<'_> {
+        JsonWriter {
+            o,
+            nonempty: vec![],
+            after_key: false,
+        }
+    }
+
+    fn separate(&mut self) -> io::Result<()> {
+        if self.after_key {
+            self.after_key = false;
+            return Ok(());
+        }
+
+        if let Some(nonempty) = self.nonempty.last_mut() {
+            if *nonempty {
+                write!(self.o, ",")?;
+            }
+
+            *nonempty = true;
+
+            write!(self.o, "\n{}", INDENT.repeat(self.nonempty.len()))?;
+        }
+
+        Ok(())
+    }
+
+    fn begin(&mut self, bracket: char) -> io::Result<()> {
+        self.separate()?;
+        self.nonempty.push(false);
+
+        write!(self.o, "{}", bracket)
+    }
+
+    fn end(&mut self, bracket: char) -> io::Result<()> {
+        if self.nonempty.pop() == Some(true) {
+            write!(self.o, "\n{}", INDENT.repeat(self.nonempty.len()))?;
+        }
+
+        write!(self.o, "{}", bracket)
+    }
+
+    fn begin_object(&mut self) -> io::Result<()> {
+        self.begin('{')
+    }
+
+    fn end_object(&mut self) -> io::Result<()> {
+        self.end('}')
+    }
+
+    fn begin_array(&mut self) -> io::Result<()> {
+        self.begin('[')
+    }
+
+    fn end_array(&mut self) -> io::Result<()> {
+        self.end(']')
+    }
+
+    fn key(&mut self, key: &str) -> io::Result<()> {
+        key.write_json(self)?;
+
+        write!(self.o, ": ")?;
+
+        self.after_key = true;
+
+        Ok(())
+    }
+
+    fn field<T: ToJson + ?Sized>(&mut self, key: &str, value: &T) -> io::Result<()> {
+        self.key(key)?;
+
+        value.write_json(self)
+    }
+
+    // Starts an array member of an object
+    fn begin_array_field(&mut self, key: &str) -> io::Result<()> {
+        self.key(key)?;
+
+        self.begin_array()
+    }
+
+    fn raw(&mut self, value: &str) -> io::Result<()> {
+        self.separate()?;
+
+        write!(self.o, "{}", value)
+    }
+}
+
+impl ToJson for str {
+    fn write_json(&self, w: &mut JsonWriter) -> io::Result<()> {
+        let mut escaped = String::with_capacity(self.len() + 2);
+
+        escaped.push('"');
+
+        for ch in self.chars() {
+            match ch {
+                '"' => escaped.push_str("\\\""),
+                '\\' => escaped.push_str("\\\\"),
+                '\n' => escaped.push_str("\\n"),
+                '\t' => escaped.push_str("\\t"),
+                '\r' => escaped.push_str("\\r"),
+                c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+                c => escaped.push(c),
+            }
+        }
+
+        escaped.push('"');
+
+        w.raw(&escaped)
+    }
+}
+
+impl ToJson for String {
+    fn write_json(&self, w: &mut JsonWriter) -> io::Result<()> {
+        self.as_str().write_json(w)
+    }
+}
+
+impl ToJson for &str {
+    fn write_json(&self, w: &mut JsonWriter) -> io::Result<()> {
+        (*self).write_json(w)
+    }
+}
+
+macro_rules! impl_to_json_display {
+    ($($t:ty),*) => {
+        $(
+            impl ToJson for $t {
+                fn write_json(&self, w: &mut JsonWriter) -> io::Result<()> {
+                    w.raw(&self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_to_json_display!(u8, u16, u32, u64, usize, i64, bool);
+
+impl<T: ToJson> ToJson for Option<T> {
+    fn write_json(&self, w: &mut JsonWriter) -> io::Result<()> {
+        match self {
+            Some(value) => value.write_json(w),
+            None => w.raw("null"),
+        }
+    }
+}
+
+fn hex_string(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+// Note names and descriptions are NUL-terminated strings more often than not
+fn bytes_to_string(bytes: &[u8]) -> String {
+    let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+
+    String::from_utf8_lossy(&bytes[..end]).into_owned()
+}
+
+fn error_kind_id(kind: ErrorKind) -> &'static str {
+    match kind {
+        ErrorKind::Truncated => "truncated",
+        ErrorKind::BadMagic => "bad_magic",
+        ErrorKind::UnsupportedClass => "unsupported_class",
+        ErrorKind::UnsupportedEncoding => "unsupported_encoding",
+        ErrorKind::IntegerOverflow => "integer_overflow",
+        ErrorKind::OutOfRange => "out_of_range",
+    }
+}
+
+// Kind of a range, the name of the field for fields, and an index for numbered structures
+fn range_kind(range_type: &RangeType) -> (&'static str, Option<&'static str>, Option<u32>) {
+    match range_type {
+        RangeType::Ident => ("ident", None, None),
+        RangeType::FileHeader => ("file_header", None, None),
+        RangeType::HeaderField(field) => ("file_header_field", Some(field), None),
+        RangeType::ProgramHeader(idx) => ("program_header", None, Some(*idx)),
+        RangeType::SectionHeader(idx) => ("section_header", None, Some(*idx)),
+        RangeType::PhdrField(field) => ("program_header_field", Some(field), None),
+        RangeType::ShdrField(field) => ("section_header_field", Some(field), None),
+        RangeType::Segment(idx) => ("segment", None, Some(*idx)),
+        RangeType::Section(idx) => ("section", None, Some(*idx)),
+        // only notes are marked as subranges of segments and sections
+        RangeType::SegmentSubrange => ("note", None, None),
+        RangeType::Symbol => ("symbol", None, None),
+        RangeType::SymbolField(field) => ("symbol_field", Some(field), None),
+        RangeType::Relocation => ("relocation", None, None),
+        RangeType::RelocationField(field) => ("relocation_field", Some(field), None),
+        RangeType::Dynamic(idx) => ("dynamic_entry", None, Some(*idx)),
+        RangeType::DynamicField(field) => ("dynamic_field", Some(field), None),
+        RangeType::VersionRecord(kind, _) => (kind, None, None),
+        RangeType::VersionField(field) => ("version_field", Some(field), None),
+        RangeType::HashHeader => ("hash_header", None, None),
+        RangeType::HashField(field) => ("hash_field", Some(field), None),
+        RangeType::Entrypoint => ("entrypoint", None, None),
+    }
+}
+
+fn write_information(w: &mut JsonWriter, elf: &ParsedElf) -> io::Result<()> {
+    w.begin_array_field("information")?;
+
+    for (id, name, value) in &elf.information {
+        w.begin_object()?;
+        w.field("id", id)?;
+        w.field("name", name)?;
+        w.field("value", value)?;
+        w.end_object()?;
+    }
+
+    w.end_array()
+}
+
+fn write_phdr(w: &mut JsonWriter, phdr: &ParsedPhdr) -> io::Result<()> {
+    w.begin_object()?;
+    w.field("type", &phdr.ptype)?;
+    w.field("type_name", &ptype_to_string(phdr.ptype))?;
+    w.field("flags", &phdr.flags)?;
+    w.field("offset", &phdr.file_offset)?;
+    w.field("file_size", &phdr.file_size)?;
+    w.field("vaddr", &phdr.vaddr)?;
+    w.field("mem_size", &phdr.memsz)?;
+    w.field("align", &phdr.alignment)?;
+    w.end_object()
+}
+
+fn write_shdr(w: &mut JsonWriter, elf: &ParsedElf, shdr: &ParsedShdr) -> io::Result<()> {
+    w.begin_object()?;
+    w.field("name", elf.shnstrtab.get(shdr.name))?;
+    w.field("type", &shdr.shtype)?;
+    w.field("type_name", &shtype_to_string(shdr.shtype))?;
+    w.field("flags", &shdr.flags)?;
+    w.field("flags_string", &shflags_to_string(shdr.flags))?;
+    w.field("addr", &shdr.addr)?;
+    w.field("offset", &shdr.file_offset)?;
+    w.field("size", &shdr.size)?;
+    w.field("link", &shdr.link)?;
+    w.field("info", &shdr.info)?;
+    w.field("addralign", &shdr.addralign)?;
+    w.field("entsize", &shdr.entsize)?;
+    w.end_object()
+}
+
+fn write_note(w: &mut JsonWriter, note: &Note) -> io::Result<()> {
+    w.begin_object()?;
+    w.field("name", &bytes_to_string(&note.name))?;
+    w.field("type", &note.ntype)?;
+    w.field("desc", &hex_string(&note.desc))?;
+    w.end_object()
+}
+
+fn write_symtab(w: &mut JsonWriter, symtab: &ParsedSymTab) -> io::Result<()> {
+    w.begin_object()?;
+    w.field("section", &symtab.section)?;
+    w.begin_array_field("symbols")?;
+
+    for sym in &symtab.syms {
+        w.begin_object()?;
+        w.field("name", &sym.name)?;
+        w.field("value", &sym.value)?;
+        w.field("size", &sym.size)?;
+        w.field("type", &symtype_to_string(sym.stype))?;
+        w.field("bind", &symbind_to_string(sym.bind))?;
+        w.field("visibility", &symvis_to_string(sym.visibility))?;
+        w.field("shndx", &sym.section_index())?;
+        w.field("shndx_name", &shndx_to_string(sym.shndx))?;
+        w.field("versym", &sym.versym)?;
+        w.field("version", &sym.version)?;
+        w.end_object()?;
+    }
+
+    w.end_array()?;
+    w.end_object()
+}
+
+fn write_reltab(w: &mut JsonWriter, elf: &ParsedElf, reltab: &ParsedRelTab) -> io::Result<()> {
+    w.begin_object()?;
+    w.field("section", &reltab.section)?;
+    w.begin_array_field("relocations")?;
+
+    for rel in &reltab.rels {
+        w.begin_object()?;
+        w.field("offset", &rel.offset)?;
+        w.field("symbol", &rel.sym)?;
+        w.field("type", &rel.rtype)?;
+        w.field("type_name", &reltype_to_string(elf.machine, rel.rtype))?;
+        w.field("addend", &rel.addend)?;
+        w.end_object()?;
+    }
+
+    w.end_array()?;
+    w.end_object()
+}
+
+fn write_dyn(w: &mut JsonWriter, entry: &ParsedDyn) -> io::Result<()> {
+    w.begin_object()?;
+    w.field("tag", &entry.tag)?;
+    w.field("tag_name", &dtag_to_string(entry.tag))?;
+    w.field("value", &entry.value)?;
+    w.field("string", &entry.string)?;
+    w.field("file_offset", &entry.file_offset)?;
+    w.end_object()
+}
+
+fn write_verneed(w: &mut JsonWriter, verneed: &ParsedVerneed) -> io::Result<()> {
+    w.begin_object()?;
+    w.field("offset", &verneed.offset)?;
+    w.field("file", &verneed.file)?;
+    w.begin_array_field("versions")?;
+
+    for aux in &verneed.aux {
+        w.begin_object()?;
+        w.field("offset", &aux.offset)?;
+        w.field("name", &aux.name)?;
+        w.field("hash", &aux.hash)?;
+        w.field("flags", &aux.flags)?;
+        w.field("index", &aux.other)?;
+        w.end_object()?;
+    }
+
+    w.end_array()?;
+    w.end_object()
+}
+
+fn write_verdef(w: &mut JsonWriter, verdef: &ParsedVerdef) -> io::Result<()> {
+    w.begin_object()?;
+    w.field("offset", &verdef.offset)?;
+    w.field("flags", &verdef.flags)?;
+    w.field("index", &verdef.ndx)?;
+    w.field("hash", &verdef.hash)?;
+    w.begin_array_field("names")?;
+
+    for aux in &verdef.aux {
+        w.begin_object()?;
+        w.field("offset", &aux.offset)?;
+        w.field("name", &aux.name)?;
+        w.end_object()?;
+    }
+
+    w.end_array()?;
+    w.end_object()
+}
+
+fn write_hashtab(w: &mut JsonWriter, hashtab: &ParsedHashTab) -> io::Result<()> {
+    w.begin_object()?;
+    w.field("section", &hashtab.section)?;
+    w.field("gnu", &hashtab.gnu)?;
+    w.field("buckets", &hashtab.nbuckets)?;
+
+    if hashtab.gnu {
+        w.field("symoffset", &hashtab.symoffset)?;
+        w.field("bloom_size", &hashtab.bloom_size)?;
+        w.field("bloom_shift", &hashtab.bloom_shift)?;
+    }
+
+    w.begin_array_field("chain_lengths")?;
+    for len in &hashtab.chain_lengths {
+        len.write_json(w)?;
+    }
+    w.end_array()?;
+
+    w.begin_array_field("unreachable")?;
+    for name in &hashtab.unreachable {
+        name.write_json(w)?;
+    }
+    w.end_array()?;
+
+    w.begin_array_field("problems")?;
+    for problem in &hashtab.problems {
+        problem.write_json(w)?;
+    }
+    w.end_array()?;
+
+    w.end_object()
+}
+
+fn write_warnings(w: &mut JsonWriter, elf: &ParsedElf) -> io::Result<()> {
+    w.begin_array_field("warnings")?;
+
+    for warning in &elf.warnings {
+        w.begin_object()?;
+        w.field("kind", error_kind_id(warning.kind))?;
+        w.field("structure", &warning.structure)?;
+        w.field("offset", &warning.offset)?;
+        w.field("detail", &warning.detail)?;
+        w.end_object()?;
+    }
+
+    w.end_array()
+}
+
+fn write_ranges(w: &mut JsonWriter, elf: &ParsedElf) -> io::Result<()> {
+    w.begin_array_field("ranges")?;
+
+    for (start, len, range_type) in elf.ranges.iter() {
+        let (kind, field, index) = range_kind(range_type);
+
+        w.begin_object()?;
+        w.field("offset", &start)?;
+        w.field("length", &len)?;
+        w.field("kind", kind)?;
+        w.field("field", &field)?;
+        w.field("index", &index)?;
+        w.end_object()?;
+    }
+
+    w.end_array()
+}
+
+pub fn generate_json(elf: &ParsedElf) -> String {
+    let mut output = vec![];
+
+    // same as with generate_report_with_options(), writing into a Vec can't fail
+    write_json(&mut output, elf).expect("write JSON to memory");
+
+    String::from_utf8(output).expect("JSON is valid UTF-8")
+}
+
+pub fn write_json(o: &mut dyn Write, elf: &ParsedElf) -> io::Result<()> {
+    let mut w = JsonWriter::new(o);
+
+    w.begin_object()?;
+
+    w.field("schema_version", &JSON_SCHEMA_VERSION)?;
+    w.field("elfcat_version", env!("CARGO_PKG_VERSION"))?;
+    w.field("file_name", &elf.filename)?;
+    w.field("file_size", &elf.file_size)?;
+
+    write_information(&mut w, elf)?;
+
+    w.begin_array_field("program_headers")?;
+    for phdr in &elf.phdrs {
+        write_phdr(&mut w, phdr)?;
+    }
+    w.end_array()?;
+
+    w.begin_array_field("section_headers")?;
+    for shdr in &elf.shdrs {
+        write_shdr(&mut w, elf, shdr)?;
+    }
+    w.end_array()?;
+
+    w.begin_array_field("notes")?;
+    for note in &elf.notes {
+        write_note(&mut w, note)?;
+    }
+    w.end_array()?;
+
+    w.begin_array_field("symbol_tables")?;
+    for symtab in &elf.symtabs {
+        write_symtab(&mut w, symtab)?;
+    }
+    w.end_array()?;
+
+    w.begin_array_field("relocation_tables")?;
+    for reltab in &elf.reltabs {
+        write_reltab(&mut w, elf, reltab)?;
+    }
+    w.end_array()?;
+
+    w.begin_array_field("dynamic")?;
+    for entry in &elf.dynamic {
+        write_dyn(&mut w, entry)?;
+    }
+    w.end_array()?;
+
+    w.begin_array_field("version_needs")?;
+    for verneed in &elf.verneeds {
+        write_verneed(&mut w, verneed)?;
+    }
+    w.end_array()?;
+
+    w.begin_array_field("version_definitions")?;
+    for verdef in &elf.verdefs {
+        write_verdef(&mut w, verdef)?;
+    }
+    w.end_array()?;
+
+    w.begin_array_field("hash_tables")?;
+    for hashtab in &elf.hashtabs {
+        write_hashtab(&mut w, hashtab)?;
+    }
+    w.end_array()?;
+
+    w.begin_array_field("segment_sections")?;
+    for sections in &elf.segment_sections {
+        w.begin_array()?;
+        for section in sections {
+            section.write_json(&mut w)?;
+        }
+        w.end_array()?;
+    }
+    w.end_array()?;
+
+    write_warnings(&mut w, elf)?;
+
+    write_ranges(&mut w, elf)?;
+
+    w.end_object()?;
+
+    writeln!(w.o)?;
+
+    w.o.flush()
+}
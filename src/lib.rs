@@ -0,0 +1,39 @@
+//! ELF visualizer: parses ELF files and generates interactive HTML reports of their layout.
+//!
+//! ```no_run
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let contents = elfcat::Input::open("a.out")?;
+//! let elf = elfcat::ParsedElf::from_bytes("a.out", &contents)?;
+//! let report = elfcat::generate_report(&elf);
+//! # Ok(())
+//! # }
+//! ```
+
+#![allow(
+    clippy::uninlined_format_args,
+    clippy::module_name_repetitions,
+    clippy::wildcard_imports
+)]
+
+mod ansi_gen;
+mod elf;
+mod input;
+mod json_gen;
+mod report_gen;
+mod text_gen;
+mod utils;
+
+pub use ansi_gen::{generate_hexdump, write_hexdump, HexdumpOptions};
+pub use elf::error::{ElfError, ErrorKind};
+pub use elf::parser::{
+    HeaderTable, Note, ParsedDyn, ParsedElf, ParsedHashTab, ParsedPhdr, ParsedRel, ParsedRelTab,
+    ParsedShdr, ParsedSym, ParsedSymTab, ParsedVerdaux, ParsedVerdef, ParsedVernaux, ParsedVerneed,
+    RangeType, Ranges, StrTab,
+};
+pub use input::Input;
+pub use json_gen::{generate_json, write_json, JSON_SCHEMA_VERSION};
+pub use report_gen::{
+    generate_report, generate_report_with_options, write_report, DumpContents, ReportOptions,
+    MAX_COLUMNS,
+};
+pub use text_gen::{generate_text, write_text};
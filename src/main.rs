@@ -4,45 +4,573 @@
     clippy::wildcard_imports
 )]
 
-mod elf;
-mod report_gen;
-mod utils;
+use elfcat::{DumpContents, HexdumpOptions, Input, ParsedElf, ReportOptions, MAX_COLUMNS};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::process::Command;
 
-use elf::parser::ParsedElf;
-use utils::MaybeError;
+// name of the input file which is read from stdin, and of the output which is stdout
+const STDIO: &str = "-";
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Html,
+    Json,
+    Text,
+    Hexdump,
+}
+
+impl Format {
+    fn from_name(name: &str) -> Option<Format> {
+        match name {
+            "html" => Some(Format::Html),
+            "json" => Some(Format::Json),
+            "text" => Some(Format::Text),
+            "hexdump" => Some(Format::Hexdump),
+            _ => None,
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            Format::Html => "html",
+            Format::Json => "json",
+            Format::Text => "txt",
+            Format::Hexdump => "ansi",
+        }
+    }
+
+    // text and hex dumps are printed unless an output is given
+    fn prints(self) -> bool {
+        matches!(self, Format::Text | Format::Hexdump)
+    }
+}
+
+enum Destination {
+    Stdout,
+    File(PathBuf),
+}
+
+struct Arguments {
+    files: Vec<String>,
+    // file, directory or "-" for stdout
+    output: Option<String>,
+    format: Format,
+    report: ReportOptions,
+    hexdump: HexdumpOptions,
+    open: bool,
+}
+
+// name of the output file for an input file: its stem with the extension of the format
+fn construct_filename(filename: &str, extension: &str) -> Option<String> {
+    let name = Path::new(filename).file_stem()?.to_str()?.to_string() + "." + extension;
+
+    Some(name)
+}
+
+impl Arguments {
+    fn destination(&self, file: &str) -> Result<Destination, String> {
+        let default_name = || {
+            let name = if file == STDIO { "stdin" } else { file };
+
+            construct_filename(name, self.format.extension())
+                .ok_or_else(|| format!("construct output filename for \"{}\"", file))
+        };
+
+        match self.output.as_deref() {
+            Some(STDIO) => Ok(Destination::Stdout),
+            Some(dir) if Path::new(dir).is_dir() => {
+                Ok(Destination::File(Path::new(dir).join(default_name()?)))
+            }
+            Some(path) => Ok(Destination::File(PathBuf::from(path))),
+            None if self.format.prints() || file == STDIO => Ok(Destination::Stdout),
+            None => Ok(Destination::File(PathBuf::from(default_name()?))),
+        }
+    }
+}
 
 fn main() {
-    let filename = parse_arguments();
-    let contents = std::fs::read(&filename).or_exit(&format!("read file \"{}\"", filename));
-    let elf = ParsedElf::from_bytes(&filename, &contents).or_exit("parse ELF");
-    let report_filename = utils::construct_filename(&filename).or_exit("construct filename");
-    let report = report_gen::generate_report(&elf);
+    let args = match parse_arguments(std::env::args().skip(1)) {
+        Ok(Action::Process(args)) => args,
+        Ok(Action::Help) => usage(),
+        Ok(Action::Version) => {
+            println!("elfcat {}", env!("CARGO_PKG_VERSION"));
+            return;
+        }
+        Err(message) => error(&message),
+    };
+    let mut failed = false;
+
+    // a broken file doesn't stop the rest from being processed
+    for file in &args.files {
+        if let Err(message) = process(file, &args) {
+            eprintln!("Failed to {}", message);
+            failed = true;
+        }
+    }
 
-    std::fs::write(report_filename, report).or_exit("write report");
+    if failed {
+        std::process::exit(1);
+    }
 }
 
-fn parse_arguments() -> String {
-    let args: Vec<String> = std::env::args().collect();
+fn process(file: &str, args: &Arguments) -> Result<(), String> {
+    let contents = if file == STDIO {
+        Input::read(&mut io::stdin().lock())
+    } else {
+        Input::open(file)
+    }
+    .map_err(|e| format!("read file \"{}\": {}", file, e))?;
+    let name = if file == STDIO { "stdin" } else { file };
+    let elf = ParsedElf::from_bytes(name, &contents)
+        .map_err(|e| format!("parse ELF \"{}\": {}", file, e))?;
+    let destination = args.destination(file)?;
+
+    // the dump would be empty
+    if args.format == Format::Hexdump && args.hexdump.start >= elf.file_size {
+        eprintln!(
+            "elfcat: warning: range starts at {:#x}, past the end of \"{}\" ({} bytes)",
+            args.hexdump.start, file, elf.file_size
+        );
+    }
+
+    let result = match &destination {
+        Destination::Stdout => write_output(&mut BufWriter::new(io::stdout().lock()), &elf, args)
+            .or_else(ignore_broken_pipe),
+        Destination::File(path) => {
+            let file =
+                File::create(path).map_err(|e| format!("create \"{}\": {}", path.display(), e))?;
+
+            write_output(&mut BufWriter::new(file), &elf, args)
+        }
+    };
+
+    result.map_err(|e| format!("write output for \"{}\": {}", file, e))?;
+
+    if let (true, Destination::File(path)) = (args.open, &destination) {
+        open_in_browser(path).map_err(|e| format!("open \"{}\": {}", path.display(), e))?;
+    }
+
+    Ok(())
+}
+
+fn write_output(o: &mut dyn Write, elf: &ParsedElf, args: &Arguments) -> io::Result<()> {
+    match args.format {
+        Format::Html => elfcat::write_report(o, elf, &args.report),
+        Format::Json => elfcat::write_json(o, elf),
+        Format::Text => elfcat::write_text(o, elf),
+        Format::Hexdump => elfcat::write_hexdump(o, elf, &args.hexdump),
+    }
+}
+
+// piping the output into `head` and the like is not an error
+fn ignore_broken_pipe(e: io::Error) -> io::Result<()> {
+    if e.kind() == io::ErrorKind::BrokenPipe {
+        Ok(())
+    } else {
+        Err(e)
+    }
+}
+
+fn open_in_browser(path: &Path) -> io::Result<()> {
+    #[cfg(target_os = "macos")]
+    let mut command = Command::new("open");
+
+    #[cfg(target_os = "windows")]
+    let mut command = {
+        let mut command = Command::new("cmd");
+        // the empty argument is the title of the window, not the file
+        command.args(["/C", "start", ""]);
+        command
+    };
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    let mut command = Command::new("xdg-open");
+
+    command.arg(path).spawn()?;
+
+    Ok(())
+}
+
+// Offsets are decimal or hexadecimal with a 0x prefix
+fn parse_offset(s: &str) -> Option<usize> {
+    match s.strip_prefix("0x") {
+        Some(hex) => usize::from_str_radix(hex, 16).ok(),
+        None => s.parse().ok(),
+    }
+}
+
+// START:END, where END is exclusive and can be left out, but can't come before START
+fn parse_range(s: &str) -> Option<(usize, Option<usize>)> {
+    let (start, end) = s.split_once(':')?;
+    let start = parse_offset(start)?;
+    let end = if end.is_empty() { None } else { Some(parse_offset(end)?) };
+
+    if end.is_some_and(|end| end <= start) {
+        return None;
+    }
+
+    Some((start, end))
+}
+
+// What the command line asks for
+enum Action {
+    Process(Arguments),
+    Help,
+    Version,
+}
+
+// Arguments without the program name
+fn parse_arguments(args: impl IntoIterator<Item = String>) -> Result<Action, String> {
+    let mut args = args.into_iter();
+    let mut files = vec![];
+    let mut output = None;
+    let mut format = Format::Html;
+    let mut headers_only = false;
+    let mut sections = vec![];
+    let mut range = None;
+    let mut columns = None;
+    let mut theme = None;
+    let mut open = false;
+    let mut only_files = false;
+
+    while let Some(arg) = args.next() {
+        if only_files || arg == STDIO || !arg.starts_with('-') {
+            files.push(arg);
+            continue;
+        }
+
+        // long options can also be given as --name=value
+        let (name, mut inline_value) = match arg.split_once('=') {
+            Some((name, value)) if name.starts_with("--") => {
+                (name.to_string(), Some(value.to_string()))
+            }
+            _ => (arg.clone(), None),
+        };
+        let mut value = || {
+            inline_value
+                .take()
+                .or_else(|| args.next())
+                .ok_or_else(|| format!("option '{}' requires a value", name))
+        };
 
-    if args.len() != 2 {
-        usage(1);
+        match name.as_str() {
+            "-h" | "--help" => return Ok(Action::Help),
+            "-v" | "--version" => return Ok(Action::Version),
+            "--" => only_files = true,
+            "-o" | "--output" => output = Some(value()?),
+            "--format" => {
+                let value = value()?;
+
+                format = Format::from_name(&value).ok_or_else(|| {
+                    format!("unknown format '{}', expected html, json, text or hexdump", value)
+                })?;
+            }
+            "--headers-only" => headers_only = true,
+            "--section" => sections.push(value()?),
+            "--range" => {
+                let value = value()?;
+
+                range = Some(
+                    parse_range(&value)
+                        .ok_or_else(|| format!("invalid range '{}', expected <from:to>", value))?,
+                );
+            }
+            "--columns" => {
+                let value = value()?;
+
+                columns = Some(
+                    value
+                        .parse::<NonZeroUsize>()
+                        .ok()
+                        .filter(|n| n.get() <= MAX_COLUMNS)
+                        .ok_or_else(|| {
+                            format!(
+                                "invalid number of columns '{}', expected 1 to {}",
+                                value, MAX_COLUMNS
+                            )
+                        })?,
+                );
+            }
+            "--theme" => {
+                let path = value()?;
+
+                theme = Some(
+                    std::fs::read_to_string(&path)
+                        .map_err(|e| format!("cannot read theme file \"{}\": {}", path, e))?,
+                );
+            }
+            "--open" => open = true,
+            _ => return Err(format!("unknown option '{}'", name)),
+        }
+
+        if inline_value.is_some() {
+            return Err(format!("option '{}' doesn't take a value", name));
+        }
+    }
+
+    if files.is_empty() {
+        return Err("no input files".to_string());
+    }
+
+    if files.iter().filter(|&file| file == STDIO).count() > 1 {
+        return Err("stdin can be read only once".to_string());
+    }
+
+    if !sections.is_empty() && !headers_only {
+        return Err("--section requires --headers-only".to_string());
+    }
+
+    if headers_only && format != Format::Html {
+        return Err("--headers-only applies only to --format html".to_string());
+    }
+
+    if range.is_some() && format != Format::Hexdump {
+        return Err("--range applies only to --format hexdump".to_string());
     }
 
-    if args[1] == "-h" || args[1] == "--help" {
-        usage(0);
+    if columns.is_some() && !matches!(format, Format::Html | Format::Hexdump) {
+        return Err("--columns applies only to --format html and hexdump".to_string());
     }
 
-    if args[1] == "-v" || args[1] == "--version" {
-        println!("elfcat {}", env!("CARGO_PKG_VERSION"));
-        std::process::exit(0);
+    if theme.is_some() && format != Format::Html {
+        return Err("--theme applies only to --format html".to_string());
     }
 
-    args[1].clone()
+    if open && format != Format::Html {
+        return Err("--open applies only to --format html".to_string());
+    }
+
+    if files.len() > 1 {
+        let message = match output.as_deref() {
+            // reports in HTML and JSON can't be concatenated
+            Some(STDIO) if !format.prints() => Some("several reports can't be written to stdout"),
+            Some(STDIO) | None => None,
+            Some(dir) if Path::new(dir).is_dir() => None,
+            Some(_) => Some("--output must be a directory when there are several input files"),
+        };
+
+        if let Some(message) = message {
+            return Err(message.to_string());
+        }
+    }
+
+    let stdin_to_stdout = output.is_none() && files.iter().any(|file| file == STDIO);
+
+    if open && (output.as_deref() == Some(STDIO) || stdin_to_stdout) {
+        return Err("--open requires reports to be written to files".to_string());
+    }
+
+    let (start, end) = range.unwrap_or((0, None));
+    let report = ReportOptions::default();
+    let hexdump = HexdumpOptions::default();
+    let dump = if headers_only {
+        DumpContents::HeadersAnd(sections)
+    } else {
+        DumpContents::Everything
+    };
+
+    Ok(Action::Process(Arguments {
+        files,
+        output,
+        format,
+        report: ReportOptions {
+            progress: true,
+            dump,
+            columns: columns.unwrap_or(report.columns),
+            theme,
+            ..report
+        },
+        hexdump: HexdumpOptions {
+            start,
+            end,
+            columns: columns.unwrap_or(hexdump.columns),
+            ..hexdump
+        },
+        open,
+    }))
+}
+
+fn error(message: &str) -> ! {
+    eprintln!("elfcat: {}", message);
+    eprintln!("Try 'elfcat --help' for more information.");
+
+    std::process::exit(1);
+}
+
+fn usage() -> ! {
+    println!("Usage: elfcat [options] <file>...");
+    println!("Writes <file>.<format> to CWD (html, json) or prints to stdout (text, hexdump).");
+    println!("A file named - is read from stdin, and its output goes to stdout.");
+    println!();
+    println!("  -o, --output <path> File to write to, - for stdout, or a directory to write");
+    println!("                      <file>.<format> into. Must be a directory or - when there");
+    println!("                      are several files. Reports of stdin go to stdout by default");
+    println!("  --format <format>   html: interactive report (default)");
+    println!("                      json: the parsed model, see json_schema.md");
+    println!("                      text: tables of the report as plain text, printed");
+    println!("                      hexdump: hex dump colored for terminals, printed");
+    println!("  --headers-only      Dump only the file, program and section headers (html)");
+    println!("  --section <name>    Also dump contents of a section, can be repeated (html)");
+    println!("  --range <from:to>   Part of the file to dump, such as 0x40:0x400 (hexdump)");
+    println!("  --columns <n>       Bytes per row of the hex dump, 16 by default (html, hexdump)");
+    println!("  --theme <file>      CSS to include in reports, such as custom theme colors (html)");
+    println!("  --open              Open the written reports in the browser (html)");
+    println!("  -h, --help          Print this help");
+    println!("  -v, --version       Print the version");
+
+    std::process::exit(0);
 }
 
-fn usage(ret: i32) {
-    println!("Usage: elfcat <filename>");
-    println!("Writes <filename>.html to CWD.");
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    std::process::exit(ret);
+    fn parse(args: &[&str]) -> Result<Action, String> {
+        parse_arguments(args.iter().map(|arg| arg.to_string()))
+    }
+
+    fn arguments(args: &[&str]) -> Arguments {
+        match parse(args) {
+            Ok(Action::Process(arguments)) => arguments,
+            Ok(_) => panic!("{:?} asks for help or version", args),
+            Err(message) => panic!("{:?}: {}", args, message),
+        }
+    }
+
+    fn error_of(args: &[&str]) -> String {
+        match parse(args) {
+            Err(message) => message,
+            Ok(_) => panic!("{:?} are accepted", args),
+        }
+    }
+
+    #[test]
+    fn offsets() {
+        assert_eq!(parse_offset("0"), Some(0));
+        assert_eq!(parse_offset("1024"), Some(1024));
+        assert_eq!(parse_offset("0x40"), Some(0x40));
+        assert_eq!(parse_offset("0xFF"), Some(0xff));
+        assert_eq!(parse_offset(""), None);
+        assert_eq!(parse_offset("0x"), None);
+        assert_eq!(parse_offset("-1"), None);
+        assert_eq!(parse_offset("0x-1"), None);
+        assert_eq!(parse_offset("40h"), None);
+        assert_eq!(parse_offset("99999999999999999999999"), None);
+    }
+
+    #[test]
+    fn ranges() {
+        assert_eq!(parse_range("0x40:0x400"), Some((0x40, Some(0x400))));
+        assert_eq!(parse_range("16:"), Some((16, None)));
+        assert_eq!(parse_range("0:1"), Some((0, Some(1))));
+        assert_eq!(parse_range("0x40"), None);
+        assert_eq!(parse_range(":0x40"), None);
+        assert_eq!(parse_range("0x40:0x40"), None);
+        assert_eq!(parse_range("0x400:0x40"), None);
+        assert_eq!(parse_range("1:2:3"), None);
+    }
+
+    #[test]
+    fn columns() {
+        let args = arguments(&["--columns", "1", "a.out"]);
+        assert_eq!(args.report.columns.get(), 1);
+        assert_eq!(args.hexdump.columns.get(), 1);
+
+        let max = MAX_COLUMNS.to_string();
+        let args = arguments(&["--format=hexdump", "--columns", &max, "a.out"]);
+        assert_eq!(args.hexdump.columns.get(), MAX_COLUMNS);
+
+        let expected =
+            |n: &str| format!("invalid number of columns '{}', expected 1 to {}", n, MAX_COLUMNS);
+        let too_many = (MAX_COLUMNS + 1).to_string();
+
+        for n in ["0", "-1", "x", "", &too_many] {
+            assert_eq!(error_of(&["--columns", n, "a.out"]), expected(n));
+        }
+    }
+
+    #[test]
+    fn files_and_dashes() {
+        let args = arguments(&["-", "a.out", "--", "-o", "--format", "--"]);
+        assert_eq!(args.files, ["-", "a.out", "-o", "--format", "--"]);
+        assert!(args.output.is_none());
+
+        let args = arguments(&["-o", "-", "--format=text", "-", "a.out"]);
+        assert_eq!(args.output.as_deref(), Some("-"));
+        assert_eq!(args.files, ["-", "a.out"]);
+
+        assert_eq!(error_of(&["-", "--", "-"]), "stdin can be read only once");
+        assert_eq!(error_of(&["--"]), "no input files");
+        assert_eq!(error_of(&[]), "no input files");
+    }
+
+    #[test]
+    fn options() {
+        let args = arguments(&["--format", "hexdump", "--range=0x40:", "a.out"]);
+        assert!(args.format == Format::Hexdump);
+        assert_eq!((args.hexdump.start, args.hexdump.end), (0x40, None));
+
+        let args = arguments(&["--headers-only", "--section", ".text", "--open", "a.out"]);
+        assert!(matches!(&args.report.dump, DumpContents::HeadersAnd(s) if s == &[".text"]));
+        assert!(args.open);
+
+        assert!(matches!(parse(&["a.out", "--help", "--bad"]), Ok(Action::Help)));
+        assert!(matches!(parse(&["-v"]), Ok(Action::Version)));
+    }
+
+    #[test]
+    fn invalid_values() {
+        let cases = [
+            (&["--range", "0x40", "a"][..], "invalid range '0x40', expected <from:to>"),
+            (&["--range=2:1", "a"], "invalid range '2:1', expected <from:to>"),
+            (
+                &["--format", "pdf", "a"],
+                "unknown format 'pdf', expected html, json, text or hexdump",
+            ),
+            (&["--bad", "a"], "unknown option '--bad'"),
+            (&["--open=yes", "a"], "option '--open' doesn't take a value"),
+            (&["a", "--output"], "option '--output' requires a value"),
+            (&["--theme", "no/such/theme.css", "a"], "cannot read theme file"),
+        ];
+
+        for (args, message) in cases {
+            assert!(error_of(args).starts_with(message), "{:?}", args);
+        }
+    }
+
+    #[test]
+    fn inapplicable_options() {
+        let cases = [
+            (&["--section", ".text", "a"][..], "--section requires --headers-only"),
+            (
+                &["--headers-only", "--format", "json", "a"],
+                "--headers-only applies only to --format html",
+            ),
+            (&["--range", "0:1", "a"], "--range applies only to --format hexdump"),
+            (
+                &["--columns", "8", "--format", "text", "a"],
+                "--columns applies only to --format html and hexdump",
+            ),
+            (
+                &["--theme", "Cargo.toml", "--format", "json", "a"],
+                "--theme applies only to --format html",
+            ),
+            (&["--open", "--format", "json", "a"], "--open applies only to --format html"),
+            (&["--open", "-"], "--open requires reports to be written to files"),
+            (&["-o", "-", "a", "b"], "several reports can't be written to stdout"),
+            (
+                &["-o", "no/such/dir", "a", "b"],
+                "--output must be a directory when there are several input files",
+            ),
+        ];
+
+        for (args, message) in cases {
+            assert_eq!(error_of(args), message, "{:?}", args);
+        }
+
+        assert_eq!(arguments(&["-o", ".", "a", "b"]).output.as_deref(), Some("."));
+    }
 }
@@ -1,10 +1,23 @@
 use crate::elf::defs::*;
-use crate::elf::parser::{Note, ParsedElf, ParsedPhdr, ParsedShdr, RangeType};
+use crate::elf::parser::{
+    file_slice, HeaderTable, Note, ParsedDyn, ParsedElf, ParsedHashTab, ParsedPhdr, ParsedRelTab,
+    ParsedShdr, ParsedSymTab, RangeType,
+};
 use crate::utils;
-use std::fmt::Write;
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::num::NonZeroUsize;
 
 const INDENT: &str = "  ";
 const DEFAULT_COLUMNS: usize = 16;
+pub const MAX_COLUMNS: usize = 256;
+// the kernel maps segments in pages, which are assumed to be of the most common size
+const PAGE_SIZE: usize = 4096;
+const PROGRESS_MIN_FILE_SIZE: usize = 16 * 1024 * 1024;
+const PROGRESS_STEP: usize = 1024 * 1024;
+// a multiple of 3, so that only the last line of base64 data is padded
+const BASE64_LINE_BYTES: usize = 57;
+const BASE64_BATCH_SIZE: usize = BASE64_LINE_BYTES * 1024;
 
 fn indent(level: usize, line: &str) -> String {
     if line.is_empty() {
@@ -63,34 +76,84 @@ macro_rules! wrow {
 macro_rules! w {
     ($dst:expr, $indent_level:expr, $($arg:tt)*) => {
         wnonl!($dst, $indent_level, $( $arg )* );
-        writeln!($dst, "").unwrap();
+        writeln!($dst)?;
     }
 }
 
 macro_rules! wnonl {
     ($dst:expr, $indent_level:expr, $($arg:tt)*) => {
-        write!($dst, "{}", INDENT.repeat($indent_level)).unwrap();
-        write!($dst, $( $arg )* ).unwrap();
+        write!($dst, "{}", INDENT.repeat($indent_level))?;
+        write!($dst, $( $arg )* )?;
     }
 }
 
-fn generate_head(o: &mut String, elf: &ParsedElf) -> Option<()> {
+pub struct ReportOptions {
+    // page title, the file name by default
+    pub title: Option<String>,
+    // connect related structures and fields with arrows
+    pub arrows: bool,
+    // print progress of generating the hex dump of big files on stderr
+    pub progress: bool,
+    pub dump: DumpContents,
+    // bytes per row of the hex dump, up to MAX_COLUMNS. It can be changed on the page too.
+    pub columns: NonZeroUsize,
+    // CSS included after the built-in stylesheet, such as colors of a custom theme
+    pub theme: Option<String>,
+}
+
+// What parts of the file are included in the hex dump
+pub enum DumpContents {
+    Everything,
+    // file, program and section headers, and sections with given names
+    HeadersAnd(Vec<String>),
+}
+
+impl Default for ReportOptions {
+    fn default() -> ReportOptions {
+        ReportOptions {
+            title: None,
+            arrows: true,
+            progress: false,
+            dump: DumpContents::Everything,
+            columns: NonZeroUsize::new(DEFAULT_COLUMNS).expect("default columns aren't zero"),
+            theme: None,
+        }
+    }
+}
+
+fn generate_head(o: &mut dyn Write, elf: &ParsedElf, options: &ReportOptions) -> io::Result<()> {
     let stylesheet: String = include_str!("style.css").indent_lines(3);
     let viewport = "width=900, initial-scale=1";
 
     w!(o, 1, "<head>");
     w!(o, 2, "<meta charset='utf-8'>");
     w!(o, 2, "<meta name='viewport' content='{}'>", viewport);
-    w!(o, 2, "<title>{}</title>", utils::basename(&elf.filename)?);
+    let title = match &options.title {
+        Some(title) => utils::html_escape_str(title),
+        None => utils::html_escape_str(utils::basename(&elf.filename).unwrap_or(&elf.filename)),
+    };
+
+    w!(o, 2, "<title>{}</title>", title);
     w!(o, 2, "<style>");
     wnonl!(o, 0, "{}", stylesheet);
     w!(o, 2, "</style>");
+
+    if let Some(theme) = &options.theme {
+        w!(o, 2, "<style id='user_theme'>");
+        wnonl!(o, 0, "{}", theme.indent_lines(3));
+        w!(o, 2, "</style>");
+    }
+
+    // in the head, so that the page is shown in the chosen theme from the start
+    w!(o, 2, "<script type='text/javascript'>");
+    wnonl!(o, 0, "{}", include_str!("js/theme.js").indent_lines(3));
+    w!(o, 2, "</script>");
     w!(o, 1, "</head>");
 
-    Some(())
+    Ok(())
 }
 
-fn generate_svg_element(o: &mut String) {
+fn generate_svg_element(o: &mut dyn Write) -> io::Result<()> {
     w!(o, 2, "<svg width='100%' height='100%'>");
 
     w!(o, 3, "<defs>");
@@ -105,17 +168,47 @@ fn generate_svg_element(o: &mut String) {
 
     w!(o, 3, "</defs>");
 
-    wnonl!(o, 3, "<g id='arrows' stroke='black' ");
+    wnonl!(o, 3, "<g id='arrows' ");
     wnonl!(o, 0, "stroke-width='1' marker-end='url(#arrowhead)'>");
     w!(o, 0, "</g>");
 
     w!(o, 2, "</svg>");
+
+    Ok(())
 }
 
-fn generate_file_info_table(o: &mut String, elf: &ParsedElf) {
+// Numbers are highlighted together with their fields in the file header
+fn format_header_table(table: &HeaderTable, prefix: &str) -> String {
+    format!(
+        "<span title='{:#x}' class='number fileinfo_e_{p}num'>{}</span> * \
+         <span title='{:#x}' class='number fileinfo_e_{p}entsize'>{}</span> @ \
+         <span title='{:#x}' class='number fileinfo_e_{p}off'>{}</span>",
+        table.num,
+        table.num,
+        table.entsize,
+        table.entsize,
+        table.offset,
+        table.offset,
+        p = prefix
+    )
+}
+
+fn generate_file_info_table(o: &mut dyn Write, elf: &ParsedElf) -> io::Result<()> {
     w!(o, 2, "<table>");
 
     for (cl, desc, value) in &elf.information {
+        let value = match *cl {
+            "e_entry" => match elf.vaddr_to_offset(elf.entry) {
+                Some(offset) if elf.entry != 0 => {
+                    format!("{} (offset {})", hex_dualfmt!(elf.entry), hex_dualfmt!(offset))
+                }
+                _ => hex_dualfmt!(elf.entry),
+            },
+            "ph" => format_header_table(&elf.phdr_table, "ph"),
+            "sh" => format_header_table(&elf.shdr_table, "sh"),
+            _ => utils::html_escape_str(value),
+        };
+
         wnonl!(o, 3, "<tr class='fileinfo_{}'> ", cl);
         wnonl!(o, 0, "<td>{}:</td> ", desc);
         wnonl!(o, 0, "<td>{}</td> ", value);
@@ -123,9 +216,38 @@ fn generate_file_info_table(o: &mut String, elf: &ParsedElf) {
     }
 
     w!(o, 2, "</table>");
+
+    Ok(())
 }
 
-fn generate_help(o: &mut String) {
+fn generate_problems_table(o: &mut dyn Write, elf: &ParsedElf) -> io::Result<()> {
+    if elf.warnings.is_empty() {
+        return Ok(());
+    }
+
+    w!(o, 2, "<table id='problems'>");
+    w!(o, 3, "<th colspan='4'>Problems</th>");
+
+    for warning in &elf.warnings {
+        let offset = match warning.offset {
+            Some(offset) => hex_dualfmt!(offset),
+            None => String::new(),
+        };
+
+        wnonl!(o, 3, "<tr> ");
+        wnonl!(o, 0, "<td>{}</td> ", warning.kind);
+        wnonl!(o, 0, "<td>{}</td> ", utils::html_escape_str(&warning.structure));
+        wnonl!(o, 0, "<td>{}</td> ", offset);
+        wnonl!(o, 0, "<td>{}</td> ", utils::html_escape_str(&warning.detail));
+        w!(o, 0, "</tr>");
+    }
+
+    w!(o, 2, "</table>");
+
+    Ok(())
+}
+
+fn generate_help(o: &mut dyn Write) -> io::Result<()> {
     let legend_items = [
         ("ident", "ELF Identification"),
         ("ehdr", "ELF Header"),
@@ -139,7 +261,9 @@ fn generate_help(o: &mut String) {
                      The middle column is the file dump. It has ELF structs, sections and segments \
                      highlighted. Some fields that reference areas in the file are clickable and \
                      connected with arrows. The rightmost column shows printable ASCII characters \
-                     corresponding to the file bytes.";
+                     corresponding to the file bytes, highlighted the same way. Press / or click \
+                     Search to find bytes, text, offsets or virtual addresses. Clicking on the \
+                     dump or on an offset puts a link to it into the address bar.";
 
     w!(o, 4, "<p>{}</p>", help_text);
 
@@ -149,9 +273,52 @@ fn generate_help(o: &mut String) {
         w!(o, 5, "<li><span class='legend_rect {}'></span>{}</li>", class, desc);
     }
     w!(o, 4, "</ul>");
+
+    Ok(())
+}
+
+// Sections can only be mapped to segments if there are both
+fn has_mapping(elf: &ParsedElf) -> bool {
+    !elf.phdrs.is_empty() && !elf.shdrs.is_empty()
+}
+
+// Matrix of sections and segments which contain them
+fn generate_mapping(o: &mut dyn Write, elf: &ParsedElf) -> io::Result<()> {
+    w!(o, 4, "<p>Sections in segments</p>");
+    w!(o, 4, "<table id='mapping_table' cellspacing='0'>");
+
+    wnonl!(o, 5, "<tr><th></th>");
+    for (idx, phdr) in elf.phdrs.iter().enumerate() {
+        wnonl!(o, 0, "<th title='{}'>", ptype_to_string(phdr.ptype));
+        wnonl!(o, 0, "<a class='bin_segment{}' href='#segment={}'>{}</a></th>", idx, idx, idx);
+    }
+    w!(o, 0, "</tr>");
+
+    for idx in 1..elf.shdrs.len() {
+        let name = utils::html_escape_str(elf.section_name(idx));
+
+        wnonl!(o, 5, "<tr><td>");
+        wnonl!(o, 0, "<a class='bin_section{}' href='#section={}'>{}</a></td>", idx, idx, name);
+        for sections in &elf.segment_sections {
+            if sections.contains(&idx) {
+                wnonl!(o, 0, "<td class='mapped'></td>");
+            } else {
+                wnonl!(o, 0, "<td></td>");
+            }
+        }
+        w!(o, 0, "</tr>");
+    }
+
+    w!(o, 4, "</table>");
+
+    Ok(())
 }
 
-fn generate_right_menu(o: &mut String) {
+fn generate_right_menu(
+    o: &mut dyn Write,
+    elf: &ParsedElf,
+    options: &ReportOptions,
+) -> io::Result<()> {
     let credits = format!("generated with elfcat {}", env!("CARGO_PKG_VERSION"));
     let url = "https://github.com/ruslashev/elfcat";
 
@@ -159,19 +326,90 @@ fn generate_right_menu(o: &mut String) {
 
     w!(o, 3, "<button class='textbutton' id='settings_toggle'>Settings</button>");
 
+    w!(o, 3, "<button class='textbutton' id='search_toggle'>Search</button>");
+
+    w!(o, 3, "<button class='textbutton' id='converter_toggle'>Addresses</button>");
+
+    // see generate_vmap()
+    if elf.load_segments().next().is_some() {
+        w!(o, 3, "<button class='textbutton' id='vmap_toggle'>Memory</button>");
+    }
+
+    if has_mapping(elf) {
+        w!(o, 3, "<button class='textbutton' id='mapping_toggle'>Mapping</button>");
+    }
+
     w!(o, 3, "<button class='textbutton' id='help_toggle'>Help</button>");
 
     w!(o, 3, "<div class='right_hidden' id='settings'>");
-    w!(o, 4, "<label for='arrow_opacity_range'>Arrow opacity:</label>");
-    w!(o, 4, "<input type='range' id='arrow_opacity_range' min='0' max='100' value='100'>");
+    w!(o, 4, "<div class='setting'>");
+    w!(o, 5, "<label for='arrow_opacity_range'>Arrow opacity:</label>");
+    w!(o, 5, "<input type='range' id='arrow_opacity_range' min='0' max='100' value='100'>");
+    w!(o, 4, "</div>");
+    w!(o, 4, "<div class='setting'>");
+    w!(o, 5, "<label for='theme_select'>Theme:</label>");
+    w!(o, 5, "<select id='theme_select'>");
+    w!(o, 6, "<option value='auto'>System</option>");
+    w!(o, 6, "<option value='light'>Light</option>");
+    w!(o, 6, "<option value='dark'>Dark</option>");
+    w!(o, 5, "</select>");
+    w!(o, 4, "</div>");
+    w!(o, 4, "<div class='setting'>");
+    w!(o, 5, "<input type='checkbox' id='addresses_checkbox'>");
+    w!(o, 5, "<label for='addresses_checkbox'>Virtual addresses in the offsets column</label>");
+    w!(o, 4, "</div>");
+    w!(o, 4, "<div class='setting'>");
+    w!(o, 5, "<label for='columns_input'>Bytes per row:</label>");
+    wnonl!(o, 5, "<input type='number' id='columns_input' min='1' max='{}' ", MAX_COLUMNS);
+    w!(o, 0, "value='{}'>", options.columns);
+    w!(o, 4, "</div>");
     w!(o, 3, "</div>");
 
+    w!(o, 3, "<div class='right_hidden' id='converter'>");
+    w!(o, 4, "<label for='converter_input'>Address or offset:</label>");
+    w!(o, 4, "<input type='text' id='converter_input' spellcheck='false'>");
+    w!(o, 4, "<div id='converter_result'></div>");
+    w!(o, 3, "</div>");
+
+    if has_mapping(elf) {
+        w!(o, 3, "<div class='right_hidden' id='mapping'>");
+        generate_mapping(o, elf)?;
+        w!(o, 3, "</div>");
+    }
+
     w!(o, 3, "<div class='right_hidden' id='help'>");
-    generate_help(o);
+    generate_help(o)?;
     w!(o, 3, "</div>");
+
+    Ok(())
 }
 
-fn generate_phdr_info_tables(o: &mut String, elf: &ParsedElf) {
+fn generate_search_panel(o: &mut dyn Write) -> io::Result<()> {
+    let modes = [
+        ("hex", "Hex bytes"),
+        ("text", "Text"),
+        ("utf16", "UTF-16 text"),
+        ("offset", "Offset"),
+        ("vaddr", "Virtual address"),
+    ];
+
+    w!(o, 2, "<div id='search'>");
+    w!(o, 3, "<select id='search_mode'>");
+    for (value, name) in &modes {
+        w!(o, 4, "<option value='{}'>{}</option>", value, name);
+    }
+    w!(o, 3, "</select>");
+    w!(o, 3, "<input type='text' id='search_input' spellcheck='false'>");
+    w!(o, 3, "<button class='textbutton' id='search_prev'>Prev</button>");
+    w!(o, 3, "<button class='textbutton' id='search_next'>Next</button>");
+    w!(o, 3, "<button class='textbutton' id='search_close'>Close</button>");
+    w!(o, 3, "<div id='search_status'></div>");
+    w!(o, 2, "</div>");
+
+    Ok(())
+}
+
+fn generate_phdr_info_tables(o: &mut dyn Write, elf: &ParsedElf) -> io::Result<()> {
     for (idx, phdr) in elf.phdrs.iter().enumerate() {
         let items = [
             ("Type", &ptype_to_string(phdr.ptype)),
@@ -192,9 +430,11 @@ fn generate_phdr_info_tables(o: &mut String, elf: &ParsedElf) {
 
         w!(o, 5, "</table>");
     }
+
+    Ok(())
 }
 
-fn generate_shdr_info_tables(o: &mut String, elf: &ParsedElf) {
+fn generate_shdr_info_tables(o: &mut dyn Write, elf: &ParsedElf) -> io::Result<()> {
     for (idx, shdr) in elf.shdrs.iter().enumerate() {
         let items = [
             ("Index", format!("{}", idx)),
@@ -219,6 +459,8 @@ fn generate_shdr_info_tables(o: &mut String, elf: &ParsedElf) {
 
         w!(o, 5, "</table>");
     }
+
+    Ok(())
 }
 
 fn format_string_byte(byte: u8) -> String {
@@ -235,7 +477,7 @@ fn format_string_slice(slice: &[u8]) -> String {
         .fold(String::new(), |s, b| s + &format_string_byte(*b))
 }
 
-fn generate_note_data(o: &mut String, note: &Note) {
+fn generate_note_data(o: &mut dyn Write, note: &Note) -> io::Result<()> {
     let name = if note.name.is_empty() {
         String::new()
     } else {
@@ -250,24 +492,30 @@ fn generate_note_data(o: &mut String, note: &Note) {
         let mut hash = String::new();
 
         for byte in &note.desc {
-            append_hex_byte(&mut hash, *byte);
+            utils::append_hex_byte(&mut hash, *byte);
         }
 
         wrow!(o, 6, "Build ID", hash);
     } else {
         wrow!(o, 6, "Desc", format_string_slice(&note.desc[..]));
     }
+
+    Ok(())
 }
 
-fn generate_segment_info_table(o: &mut String, elf: &ParsedElf, phdr: &ParsedPhdr) {
+fn generate_segment_info_table(
+    o: &mut dyn Write,
+    elf: &ParsedElf,
+    phdr: &ParsedPhdr,
+) -> io::Result<()> {
     match phdr.ptype {
         PT_INTERP => {
-            let interp_str = format_string_slice(
-                &elf.contents[phdr.file_offset..phdr.file_offset + phdr.file_size - 1],
-            );
+            let interp = file_slice(elf.contents, phdr.file_offset, phdr.file_size).unwrap_or(&[]);
+            let interp = interp.strip_suffix(&[0]).unwrap_or(interp);
 
-            wrow!(o, 6, "Interpreter", interp_str);
+            wrow!(o, 6, "Interpreter", format_string_slice(interp));
         }
+        PT_DYNAMIC => generate_dynamic_data(o, elf)?,
         PT_NOTE => {
             // this is really bad and made out of desperation.
             // notes stored in elf.notes don't have to have 1-to-1
@@ -275,7 +523,7 @@ fn generate_segment_info_table(o: &mut String, elf: &ParsedElf, phdr: &ParsedPhd
             for i in 0..elf.notes.len() {
                 let note = &elf.notes[i];
 
-                generate_note_data(o, note);
+                generate_note_data(o, note)?;
 
                 if i != elf.notes.len() - 1 {
                     w!(o, 6, "<tr> <td><br></td> </tr>");
@@ -284,9 +532,214 @@ fn generate_segment_info_table(o: &mut String, elf: &ParsedElf, phdr: &ParsedPhd
         }
         _ => {}
     }
+
+    Ok(())
 }
 
-fn generate_strtab_data(o: &mut String, section: &[u8]) {
+fn format_dyn_value(entry: &ParsedDyn) -> String {
+    if let Some(string) = &entry.string {
+        return utils::html_escape_str(string);
+    }
+
+    match entry.tag {
+        DT_FLAGS => dflags_to_string(entry.value),
+        DT_FLAGS_1 => dflags1_to_string(entry.value),
+        DT_PLTREL => dtag_to_string(entry.value as i64),
+        tag if dtag_is_address(tag) => match entry.file_offset {
+            Some(offset) => {
+                format!("{} (offset {})", hex_dualfmt!(entry.value), hex_dualfmt!(offset))
+            }
+            None => hex_dualfmt!(entry.value),
+        },
+        _ => dec_dualfmt!(entry.value),
+    }
+}
+
+fn generate_dynamic_data(o: &mut dyn Write, elf: &ParsedElf) -> io::Result<()> {
+    generate_entries_table_start(o, &["Tag", "Value"])?;
+
+    for entry in &elf.dynamic {
+        wnonl!(o, 9, "<tr> ");
+        wnonl!(o, 0, "<td>{}</td> ", dtag_to_string(entry.tag));
+        wnonl!(o, 0, "<td>{}</td> ", format_dyn_value(entry));
+        w!(o, 0, "</tr>");
+    }
+
+    generate_entries_table_end(o)?;
+
+    Ok(())
+}
+
+fn symbols_with_version(elf: &ParsedElf, ndx: u16) -> String {
+    let names: Vec<String> = elf
+        .symbols_with_version(ndx)
+        .into_iter()
+        .map(utils::html_escape_str)
+        .collect();
+
+    names.join(" ")
+}
+
+fn generate_verneed_data(o: &mut dyn Write, elf: &ParsedElf) -> io::Result<()> {
+    let columns = ["File", "Version", "Hash", "Flags", "Index", "Symbols"];
+
+    generate_entries_table_start(o, &columns)?;
+
+    for verneed in &elf.verneeds {
+        for (i, vernaux) in verneed.aux.iter().enumerate() {
+            let file = if i == 0 {
+                utils::html_escape_str(&verneed.file)
+            } else {
+                String::new()
+            };
+
+            wnonl!(o, 9, "<tr> ");
+            wnonl!(o, 0, "<td>{}</td> ", file);
+            wnonl!(o, 0, "<td>{}</td> ", utils::html_escape_str(&vernaux.name));
+            wnonl!(o, 0, "<td>{:#010x}</td> ", vernaux.hash);
+            wnonl!(o, 0, "<td>{}</td> ", verflags_to_string(vernaux.flags));
+            wnonl!(o, 0, "<td>{}</td> ", vernaux.other);
+            wnonl!(o, 0, "<td>{}</td> ", symbols_with_version(elf, vernaux.other));
+            w!(o, 0, "</tr>");
+        }
+    }
+
+    generate_entries_table_end(o)?;
+
+    Ok(())
+}
+
+fn generate_verdef_data(o: &mut dyn Write, elf: &ParsedElf) -> io::Result<()> {
+    generate_entries_table_start(o, &["Index", "Flags", "Version", "Hash", "Parents"])?;
+
+    for verdef in &elf.verdefs {
+        let mut names = verdef
+            .aux
+            .iter()
+            .map(|aux| utils::html_escape_str(&aux.name));
+        let name = names.next().unwrap_or_default();
+        let parents: Vec<String> = names.collect();
+
+        wnonl!(o, 9, "<tr> ");
+        wnonl!(o, 0, "<td>{}</td> ", verdef.ndx);
+        wnonl!(o, 0, "<td>{}</td> ", verflags_to_string(verdef.flags));
+        wnonl!(o, 0, "<td>{}</td> ", name);
+        wnonl!(o, 0, "<td>{:#010x}</td> ", verdef.hash);
+        wnonl!(o, 0, "<td>{}</td> ", parents.join(" "));
+        w!(o, 0, "</tr>");
+    }
+
+    generate_entries_table_end(o)?;
+
+    Ok(())
+}
+
+fn generate_versym_data(o: &mut dyn Write, elf: &ParsedElf, shdr: &ParsedShdr) -> io::Result<()> {
+    let symtab = match elf
+        .symtabs
+        .iter()
+        .find(|symtab| symtab.section == shdr.link)
+    {
+        Some(symtab) => symtab,
+        None => return Ok(()),
+    };
+
+    generate_entries_table_start(o, &["Num", "Versym", "Symbol"])?;
+
+    for (idx, sym) in symtab.syms.iter().enumerate() {
+        let versym = match sym.versym {
+            Some(versym) => versym,
+            None => continue,
+        };
+
+        wnonl!(o, 9, "<tr> ");
+        wnonl!(o, 0, "<td>{}</td> ", idx);
+        wnonl!(o, 0, "<td>{}</td> ", hex_dualfmt!(versym));
+        wnonl!(o, 0, "<td>{}</td> ", utils::html_escape_str(&sym.name_with_version()));
+        w!(o, 0, "</tr>");
+    }
+
+    generate_entries_table_end(o)?;
+
+    Ok(())
+}
+
+fn generate_hash_data(
+    o: &mut dyn Write,
+    elf: &ParsedElf,
+    shdr: &ParsedShdr,
+    hashtab: &ParsedHashTab,
+) -> io::Result<()> {
+    wrow!(o, 6, "Symbol table", utils::html_escape_str(elf.section_name(shdr.link)));
+    wrow!(o, 6, "Buckets", dec_dualfmt!(hashtab.nbuckets));
+
+    if hashtab.gnu {
+        wrow!(o, 6, "Symbol offset", dec_dualfmt!(hashtab.symoffset));
+        wrow!(o, 6, "Bloom words", dec_dualfmt!(hashtab.bloom_size));
+        wrow!(o, 6, "Bloom shift", dec_dualfmt!(hashtab.bloom_shift));
+    }
+
+    for problem in &hashtab.problems {
+        wrow!(o, 6, "Problem(!)", problem);
+    }
+
+    if hashtab.chain_lengths.is_empty() {
+        return Ok(());
+    }
+
+    let nbuckets = hashtab.chain_lengths.len();
+    let nsyms: usize = hashtab.chain_lengths.iter().sum();
+    let max = hashtab.chain_lengths.iter().copied().max().unwrap_or(0);
+    let empty = hashtab
+        .chain_lengths
+        .iter()
+        .filter(|&&len| len == 0)
+        .count();
+
+    wrow!(o, 6, "Hashed symbols", nsyms);
+    wrow!(
+        o,
+        6,
+        "Empty buckets",
+        format!("{} ({:.1}%)", empty, 100.0 * empty as f64 / nbuckets as f64)
+    );
+    wrow!(o, 6, "Max chain length", max);
+
+    if hashtab.unreachable.is_empty() {
+        wrow!(o, 6, "Lookup check", "all exported symbols are reachable");
+    } else {
+        let names: Vec<String> = hashtab
+            .unreachable
+            .iter()
+            .map(|name| utils::html_escape_str(name))
+            .collect();
+
+        wrow!(o, 6, "Unreachable(!)", names.join(" "));
+    }
+
+    w!(o, 6, "<tr><td><br></td></tr>");
+
+    generate_entries_table_start(o, &["Length", "Buckets", "% of total", "Coverage"])?;
+
+    let mut covered = 0;
+
+    for (len, &count) in hashtab.chain_length_counts().iter().enumerate() {
+        covered += len * count;
+
+        wnonl!(o, 9, "<tr> ");
+        wnonl!(o, 0, "<td>{}</td> ", len);
+        wnonl!(o, 0, "<td>{}</td> ", count);
+        wnonl!(o, 0, "<td>{:.1}%</td> ", 100.0 * count as f64 / nbuckets as f64);
+        wnonl!(o, 0, "<td>{:.1}%</td> ", 100.0 * covered as f64 / nsyms.max(1) as f64);
+        w!(o, 0, "</tr>");
+    }
+
+    generate_entries_table_end(o)?;
+
+    Ok(())
+}
+
+fn generate_strtab_data(o: &mut dyn Write, section: &[u8]) -> io::Result<()> {
     let mut curr_start = 0;
 
     w!(o, 6, "<tr>");
@@ -313,25 +766,159 @@ fn generate_strtab_data(o: &mut String, section: &[u8]) {
     w!(o, 8, "</div>");
     w!(o, 7, "</td>");
     w!(o, 6, "</tr>");
+
+    Ok(())
 }
 
-fn generate_section_info_table(o: &mut String, elf: &ParsedElf, shdr: &ParsedShdr) {
-    let section = &elf.contents[shdr.file_offset..shdr.file_offset + shdr.size];
+fn generate_entries_table_start(o: &mut dyn Write, columns: &[&str]) -> io::Result<()> {
+    w!(o, 6, "<tr>");
+    w!(o, 7, "<td colspan='2'>");
+    w!(o, 8, "<table class='entries'>");
 
-    if shdr.shtype == SHT_STRTAB {
-        generate_strtab_data(o, section);
+    wnonl!(o, 9, "<tr>");
+    for column in columns {
+        wnonl!(o, 0, " <th>{}</th>", column);
     }
+    w!(o, 0, " </tr>");
+
+    Ok(())
+}
+
+fn generate_entries_table_end(o: &mut dyn Write) -> io::Result<()> {
+    w!(o, 8, "</table>");
+    w!(o, 7, "</td>");
+    w!(o, 6, "</tr>");
+
+    Ok(())
+}
+
+fn generate_symtab_data(o: &mut dyn Write, symtab: &ParsedSymTab) -> io::Result<()> {
+    let columns = ["Num", "Value", "Size", "Type", "Bind", "Vis", "Ndx", "Name"];
+
+    generate_entries_table_start(o, &columns)?;
+
+    for (idx, sym) in symtab.syms.iter().enumerate() {
+        wnonl!(o, 9, "<tr> ");
+        wnonl!(o, 0, "<td>{}</td> ", idx);
+        wnonl!(o, 0, "<td>{}</td> ", hex_dualfmt!(sym.value));
+        wnonl!(o, 0, "<td>{}</td> ", dec_dualfmt!(sym.size));
+        wnonl!(o, 0, "<td>{}</td> ", symtype_to_string(sym.stype));
+        wnonl!(o, 0, "<td>{}</td> ", symbind_to_string(sym.bind));
+        wnonl!(o, 0, "<td>{}</td> ", symvis_to_string(sym.visibility));
+        wnonl!(o, 0, "<td>{}</td> ", sym.shndx_string());
+        wnonl!(o, 0, "<td>{}</td> ", utils::html_escape_str(&sym.name_with_version()));
+        w!(o, 0, "</tr>");
+    }
+
+    generate_entries_table_end(o)?;
+
+    Ok(())
+}
+
+fn generate_reltab_data(
+    o: &mut dyn Write,
+    elf: &ParsedElf,
+    shdr: &ParsedShdr,
+    reltab: &ParsedRelTab,
+) -> io::Result<()> {
+    let symtab = elf
+        .symtabs
+        .iter()
+        .find(|symtab| symtab.section == shdr.link);
+    let has_addend = shdr.shtype == SHT_RELA;
+
+    if shdr.info != 0 {
+        wrow!(o, 6, "Applies to", utils::html_escape_str(elf.section_name(shdr.info)));
+    }
+
+    wrow!(o, 6, "Symbol table", utils::html_escape_str(elf.section_name(shdr.link)));
+
+    w!(o, 6, "<tr><td><br></td></tr>");
+
+    let columns: &[&str] = if has_addend {
+        &["Offset", "Type", "Sym", "Symbol name", "Addend"]
+    } else {
+        &["Offset", "Type", "Sym", "Symbol name"]
+    };
+
+    generate_entries_table_start(o, columns)?;
+
+    for rel in &reltab.rels {
+        let name = elf.rel_symbol_name(symtab, rel.sym);
+
+        wnonl!(o, 9, "<tr> ");
+        wnonl!(o, 0, "<td>{}</td> ", hex_dualfmt!(rel.offset));
+        wnonl!(o, 0, "<td>{}</td> ", reltype_to_string(elf.machine, rel.rtype));
+        wnonl!(o, 0, "<td>{}</td> ", rel.sym);
+        wnonl!(o, 0, "<td>{}</td> ", utils::html_escape_str(name));
+        if let Some(addend) = rel.addend {
+            wnonl!(o, 0, "<td>{}</td> ", dec_dualfmt!(addend));
+        }
+        w!(o, 0, "</tr>");
+    }
+
+    generate_entries_table_end(o)?;
+
+    Ok(())
+}
+
+fn generate_section_info_table(
+    o: &mut dyn Write,
+    elf: &ParsedElf,
+    idx: usize,
+    shdr: &ParsedShdr,
+) -> io::Result<()> {
+    let section = elf.section_bytes(idx);
+
+    match shdr.shtype {
+        SHT_STRTAB => generate_strtab_data(o, section)?,
+        SHT_SYMTAB | SHT_DYNSYM => {
+            if let Some(symtab) = elf.symtabs.iter().find(|symtab| symtab.section == idx) {
+                generate_symtab_data(o, symtab)?;
+            }
+        }
+        SHT_DYNAMIC => generate_dynamic_data(o, elf)?,
+        SHT_VER_NEED => generate_verneed_data(o, elf)?,
+        SHT_VER_DEF => generate_verdef_data(o, elf)?,
+        SHT_VER_SYM => generate_versym_data(o, elf, shdr)?,
+        SHT_HASH | SHT_GNU_HASH => {
+            if let Some(hashtab) = elf.hashtabs.iter().find(|hashtab| hashtab.section == idx) {
+                generate_hash_data(o, elf, shdr, hashtab)?;
+            }
+        }
+        SHT_REL | SHT_RELA => {
+            if let Some(reltab) = elf.reltabs.iter().find(|reltab| reltab.section == idx) {
+                generate_reltab_data(o, elf, shdr, reltab)?;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
 }
 
 fn has_segment_detail(ptype: u32) -> bool {
-    matches!(ptype, PT_INTERP | PT_NOTE)
+    matches!(ptype, PT_INTERP | PT_NOTE | PT_DYNAMIC)
 }
 
 fn has_section_detail(ptype: u32) -> bool {
-    matches!(ptype, SHT_STRTAB)
+    matches!(
+        ptype,
+        SHT_STRTAB
+            | SHT_SYMTAB
+            | SHT_DYNSYM
+            | SHT_REL
+            | SHT_RELA
+            | SHT_DYNAMIC
+            | SHT_VER_NEED
+            | SHT_VER_DEF
+            | SHT_VER_SYM
+            | SHT_HASH
+            | SHT_GNU_HASH
+    )
 }
 
-fn generate_segment_info_tables(o: &mut String, elf: &ParsedElf) {
+fn generate_segment_info_tables(o: &mut dyn Write, elf: &ParsedElf) -> io::Result<()> {
     for (idx, phdr) in elf.phdrs.iter().enumerate() {
         w!(o, 5, "<table class='conceal itable' id='info_segment{}'>", idx);
         w!(o, 5, "<th colspan='2' class='segment_itable'></th>");
@@ -340,16 +927,36 @@ fn generate_segment_info_tables(o: &mut String, elf: &ParsedElf) {
         wrow!(o, 6, "Size in file", size_dualfmt!(phdr.file_size));
         wrow!(o, 6, "Size in memory", size_dualfmt!(phdr.memsz));
 
+        if has_mapping(elf) {
+            let names: Vec<String> = elf.segment_sections[idx]
+                .iter()
+                .map(|&section| utils::html_escape_str(elf.section_name(section)))
+                .collect();
+
+            wrow!(
+                o,
+                6,
+                "Sections",
+                if names.is_empty() {
+                    "none".to_string()
+                } else {
+                    names.join(" ")
+                }
+            );
+        }
+
         if has_segment_detail(phdr.ptype) {
             w!(o, 6, "<tr><td><br></td></tr>");
-            generate_segment_info_table(o, elf, phdr);
+            generate_segment_info_table(o, elf, phdr)?;
         }
 
         w!(o, 5, "</table>");
     }
+
+    Ok(())
 }
 
-fn generate_section_info_tables(o: &mut String, elf: &ParsedElf) {
+fn generate_section_info_tables(o: &mut dyn Write, elf: &ParsedElf) -> io::Result<()> {
     for (idx, shdr) in elf.shdrs.iter().enumerate() {
         w!(o, 5, "<table class='conceal itable' id='info_section{}'>", idx);
         w!(o, 5, "<th colspan='2' class='section_itable'></th>");
@@ -357,38 +964,206 @@ fn generate_section_info_tables(o: &mut String, elf: &ParsedElf) {
         wrow!(o, 6, "Type", &shtype_to_string(shdr.shtype));
         wrow!(o, 6, "Size", size_dualfmt!(shdr.size));
 
+        if has_mapping(elf) && idx != 0 {
+            let segments: Vec<String> = elf
+                .section_segments(idx)
+                .iter()
+                .map(|&segment| {
+                    format!("{} {}", segment, ptype_to_string(elf.phdrs[segment].ptype))
+                })
+                .collect();
+
+            wrow!(
+                o,
+                6,
+                "Segments",
+                if segments.is_empty() {
+                    "none".to_string()
+                } else {
+                    segments.join(", ")
+                }
+            );
+        }
+
         if has_section_detail(shdr.shtype) {
             w!(o, 6, "<tr><td><br></td></tr>");
-            generate_section_info_table(o, elf, shdr);
+            generate_section_info_table(o, elf, idx, shdr)?;
         }
 
         w!(o, 5, "</table>");
     }
+
+    Ok(())
+}
+
+fn permissions(flags: &str) -> String {
+    let mut result = String::new();
+
+    for (flag, letter) in [('R', 'r'), ('W', 'w'), ('X', 'x')] {
+        result.push(if flags.contains(flag) { letter } else { '-' });
+    }
+
+    result
+}
+
+fn format_area(start: usize, end: usize) -> String {
+    format!("{:#x}-{:#x}", start, end)
+}
+
+// Area which a segment type such as PT_GNU_RELRO covers in memory
+fn segment_area(elf: &ParsedElf, ptype: u32) -> Option<(usize, usize)> {
+    elf.phdrs
+        .iter()
+        .find(|phdr| phdr.ptype == ptype)
+        .map(|phdr| (phdr.vaddr, phdr.vaddr.saturating_add(phdr.memsz)))
+}
+
+fn generate_vmap_sections(
+    o: &mut dyn Write,
+    elf: &ParsedElf,
+    phdr: &ParsedPhdr,
+    relro: Option<(usize, usize)>,
+) -> io::Result<()> {
+    let file_end = phdr.vaddr.saturating_add(phdr.file_size);
+    let mem_end = phdr.vaddr.saturating_add(phdr.memsz);
+    let mut sections: Vec<(usize, &ParsedShdr)> = elf
+        .shdrs
+        .iter()
+        .enumerate()
+        .filter(|(_, shdr)| shdr.flags & SHF_ALLOC != 0)
+        .filter(|(_, shdr)| shdr.addr >= phdr.vaddr && shdr.addr < mem_end)
+        .collect();
+
+    sections.sort_by_key(|(_, shdr)| shdr.addr);
+
+    for (idx, shdr) in sections {
+        let name = utils::html_escape_str(elf.shnstrtab.get(shdr.name));
+        let end = shdr.addr.saturating_add(shdr.size);
+        let mut classes = format!("bin_section{} vmap_section", idx);
+
+        if shdr.shtype == SHT_NOBITS || shdr.addr >= file_end {
+            classes += " vmap_bss";
+        }
+
+        if relro.is_some_and(|(start, relro_end)| shdr.addr >= start && end <= relro_end) {
+            classes += " vmap_relro";
+        }
+
+        if shdr.flags & SHF_TLS != 0 {
+            classes += " vmap_tls";
+        }
+
+        wnonl!(o, 4, "<a class='{}' href='#section={}'>", classes, idx);
+        w!(o, 0, "{:#x} {} ({})</a>", shdr.addr, name, utils::human_format_bytes(shdr.size as u64));
+    }
+
+    Ok(())
 }
 
-fn generate_sticky_info_tables(o: &mut String, elf: &ParsedElf) {
+// Process image which the kernel would map: PT_LOAD segments widened to whole pages, with the
+// sections inside them
+fn generate_vmap(o: &mut dyn Write, elf: &ParsedElf) -> io::Result<()> {
+    let mut loads: Vec<(usize, &ParsedPhdr)> = elf.load_segments().collect();
+    let relro = segment_area(elf, PT_GNU_RELRO);
+    let tls = segment_area(elf, PT_TLS);
+    let mut prev_end = None;
+
+    if loads.is_empty() {
+        return Ok(());
+    }
+
+    loads.sort_by_key(|(_, phdr)| phdr.vaddr);
+
+    w!(o, 2, "<div id='vmap'>");
+    w!(o, 3, "<div class='vmap_title'>Memory mappings, {} KiB pages</div>", PAGE_SIZE / 1024);
+
+    for (idx, phdr) in loads {
+        let start = phdr.vaddr - phdr.vaddr % PAGE_SIZE;
+        let end = phdr
+            .vaddr
+            .saturating_add(phdr.memsz)
+            .div_ceil(PAGE_SIZE)
+            .saturating_mul(PAGE_SIZE);
+        let perms = permissions(&phdr.flags);
+
+        if let Some(prev_end) = prev_end.filter(|&prev_end| start > prev_end) {
+            w!(o, 3, "<div class='vmap_gap'>unmapped {}</div>", format_area(prev_end, start));
+        }
+
+        prev_end = Some(end);
+
+        wnonl!(o, 3, "<div class='bin_segment{} vmap_segment ", idx);
+        w!(o, 0, "perm_{}'>", perms.replace('-', ""));
+        wnonl!(o, 4, "<a class='vmap_head' href='#segment={}'>", idx);
+        w!(o, 0, "{} {} segment {}</a>", format_area(start, end), perms, idx);
+
+        let file_end = phdr.vaddr.saturating_add(phdr.file_size);
+        let mem_end = phdr.vaddr.saturating_add(phdr.memsz);
+        let overlaps =
+            |(area_start, area_end): (usize, usize)| area_start < mem_end && area_end > phdr.vaddr;
+
+        if let Some(area) = relro.filter(|&area| overlaps(area)) {
+            wnonl!(o, 4, "<div class='vmap_note vmap_relro'>");
+            w!(o, 0, "RELRO {}, read-only after relocation</div>", format_area(area.0, area.1));
+        }
+
+        if let Some(area) = tls.filter(|&area| overlaps(area)) {
+            wnonl!(o, 4, "<div class='vmap_note vmap_tls'>");
+            w!(o, 0, "TLS template {}</div>", format_area(area.0, area.1));
+        }
+
+        generate_vmap_sections(o, elf, phdr, relro)?;
+
+        if mem_end > file_end {
+            wnonl!(o, 4, "<div class='vmap_note vmap_bss'>");
+            w!(o, 0, "zero-filled {}</div>", format_area(file_end, mem_end));
+        }
+
+        w!(o, 3, "</div>");
+    }
+
+    match elf.phdrs.iter().find(|phdr| phdr.ptype == PT_GNU_STACK) {
+        Some(phdr) => {
+            let perms = permissions(&phdr.flags);
+
+            wnonl!(o, 3, "<div class='vmap_segment perm_{}'>", perms.replace('-', ""));
+            w!(o, 0, "stack {} (PT_GNU_STACK)</div>", perms);
+        }
+        None => {
+            w!(o, 3, "<div class='vmap_gap'>no PT_GNU_STACK, the stack may be executable</div>");
+        }
+    }
+
+    w!(o, 2, "</div>");
+
+    Ok(())
+}
+
+fn generate_sticky_info_tables(o: &mut dyn Write, elf: &ParsedElf) -> io::Result<()> {
     w!(o, 2, "<table id='sticky_table' cellspacing='0'>");
     w!(o, 3, "<tr>");
 
     w!(o, 4, "<td id='desc'></td>");
 
     w!(o, 4, "<td class='infotables'>");
-    generate_phdr_info_tables(o, elf);
+    generate_phdr_info_tables(o, elf)?;
 
-    generate_shdr_info_tables(o, elf);
+    generate_shdr_info_tables(o, elf)?;
     w!(o, 4, "</td>");
 
     w!(o, 4, "<td class='infotables'>");
-    generate_segment_info_tables(o, elf);
+    generate_segment_info_tables(o, elf)?;
 
-    generate_section_info_tables(o, elf);
+    generate_section_info_tables(o, elf)?;
     w!(o, 4, "</td>");
 
     w!(o, 3, "</tr>");
     w!(o, 2, "</table>");
+
+    Ok(())
 }
 
-fn add_highlight_script(o: &mut String) {
+fn add_highlight_script(o: &mut dyn Write) -> io::Result<()> {
     let classes = [
         "class",
         "data",
@@ -417,41 +1192,144 @@ fn add_highlight_script(o: &mut String) {
     }
 
     w!(o, 2, "</script>");
+
+    Ok(())
 }
 
-fn add_description_script(o: &mut String) {
+fn add_description_script(o: &mut dyn Write) -> io::Result<()> {
     w!(o, 2, "<script type='text/javascript'>");
 
     wnonl!(o, 0, "{}", include_str!("js/description.js").indent_lines(3));
 
     w!(o, 2, "</script>");
+
+    Ok(())
 }
 
-fn add_conceal_script(o: &mut String) {
+fn add_conceal_script(o: &mut dyn Write) -> io::Result<()> {
     w!(o, 2, "<script type='text/javascript'>");
 
     wnonl!(o, 0, "{}", include_str!("js/conceal.js").indent_lines(3));
 
     w!(o, 2, "</script>");
+
+    Ok(())
 }
 
-fn add_offsets_script(o: &mut String, elf: &ParsedElf) {
+fn add_dump_script(
+    o: &mut dyn Write,
+    elf: &ParsedElf,
+    options: &ReportOptions,
+    regions: &[(usize, usize)],
+    range_count: usize,
+) -> io::Result<()> {
+    let regions: Vec<String> = regions
+        .iter()
+        .map(|(start, end)| format!("[{}, {}]", start, end))
+        .collect();
+
     w!(o, 2, "<script type='text/javascript'>");
 
-    w!(o, 3, "let fileLen = {}", elf.file_size);
+    w!(o, 3, "let fileLen = {};", elf.file_size);
+    w!(o, 3, "let dumpRegions = [{}];", regions.join(", "));
+    w!(o, 3, "let rangeCount = {};", range_count);
+    w!(o, 3, "let columns = {};", options.columns);
+
+    let segments: Vec<String> = elf
+        .load_segments()
+        .map(|(idx, phdr)| {
+            format!(
+                "{{ index: {}, offset: {}, fileSize: {:#x}n, vaddr: {:#x}n, memSize: {:#x}n }}",
+                idx, phdr.file_offset, phdr.file_size, phdr.vaddr, phdr.memsz
+            )
+        })
+        .collect();
+
+    w!(o, 3, "let loadSegments = [{}];", segments.join(", "));
 
-    wnonl!(o, 0, "{}", include_str!("js/offsets.js").indent_lines(3));
+    wnonl!(o, 0, "{}", include_str!("js/dump.js").indent_lines(3));
 
     w!(o, 2, "</script>");
+
+    Ok(())
 }
 
-fn add_arrows_script(o: &mut String, elf: &ParsedElf) {
+// selector of a highlighted section or segment which starts exactly at given offset
+fn area_at_offset(elf: &ParsedElf, offset: usize) -> Option<String> {
+    let section = elf
+        .shdrs
+        .iter()
+        .position(|shdr| shdr.file_offset == offset && shdr.size != 0 && shdr.shtype != SHT_NOBITS);
+
+    if let Some(idx) = section {
+        return Some(format!(".bin_section{}", idx));
+    }
+
+    let segment = elf
+        .phdrs
+        .iter()
+        .position(|phdr| phdr.file_offset == offset && phdr.file_size != 0);
+
+    segment.map(|idx| format!(".bin_segment{}", idx))
+}
+
+// connect records of version chains: entry to its first auxiliary entry and to the next entry
+fn add_version_arrows(o: &mut dyn Write, elf: &ParsedElf) -> io::Result<()> {
+    let connect = |o: &mut dyn Write, from: usize, field: &str, to: usize| -> io::Result<()> {
+        w!(o, 3, "connect('.ver{:x} > .{}', '.ver{:x}');", from, field, to);
+
+        Ok(())
+    };
+
+    for (i, verneed) in elf.verneeds.iter().enumerate() {
+        if let Some(first) = verneed.aux.first() {
+            connect(o, verneed.offset, "vn_aux", first.offset)?;
+        }
+
+        for pair in verneed.aux.windows(2) {
+            connect(o, pair[0].offset, "vna_next", pair[1].offset)?;
+        }
+
+        if let Some(next) = elf.verneeds.get(i + 1) {
+            connect(o, verneed.offset, "vn_next", next.offset)?;
+        }
+    }
+
+    for (i, verdef) in elf.verdefs.iter().enumerate() {
+        if let Some(first) = verdef.aux.first() {
+            connect(o, verdef.offset, "vd_aux", first.offset)?;
+        }
+
+        for pair in verdef.aux.windows(2) {
+            connect(o, pair[0].offset, "vda_next", pair[1].offset)?;
+        }
+
+        if let Some(next) = elf.verdefs.get(i + 1) {
+            connect(o, verdef.offset, "vd_next", next.offset)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn add_arrows_script(
+    o: &mut dyn Write,
+    elf: &ParsedElf,
+    options: &ReportOptions,
+) -> io::Result<()> {
     w!(o, 2, "<script type='text/javascript'>");
 
     wnonl!(o, 0, "{}", include_str!("js/arrows.js").indent_lines(3));
 
+    // settings.js relies on arrows.js, so it is included either way
+    if !options.arrows {
+        w!(o, 2, "</script>");
+        return Ok(());
+    }
+
     w!(o, 3, "connect('.e_phoff', '.bin_phdr0');");
     w!(o, 3, "connect('.e_shoff', '.bin_shdr0');");
+    w!(o, 3, "connect('.e_entry', '.entrypoint');");
 
     for i in 0..elf.phdrs.len() {
         w!(o, 3, "connect('.bin_phdr{} > .p_offset', '.bin_segment{}');", i, i);
@@ -465,158 +1343,396 @@ fn add_arrows_script(o: &mut String, elf: &ParsedElf) {
         if link != 0 {
             w!(o, 3, "connect('.bin_shdr{} > .sh_link', '.bin_shdr{}');", i, link);
         }
+
+        let shdr = &elf.shdrs[i];
+
+        if (shdr.shtype == SHT_REL || shdr.shtype == SHT_RELA) && shdr.info != 0 {
+            w!(o, 3, "connect('.bin_shdr{} > .sh_info', '.bin_shdr{}');", i, shdr.info);
+        }
+    }
+
+    add_version_arrows(o, elf)?;
+
+    for (i, entry) in elf.dynamic.iter().enumerate() {
+        if let Some(target) = entry.file_offset.and_then(|off| area_at_offset(elf, off)) {
+            w!(o, 3, "connect('.dyn{} > .d_val', '{}');", i, target);
+        }
     }
 
     w!(o, 3, "pushArrowElems();");
 
     w!(o, 2, "</script>");
+
+    Ok(())
 }
 
-fn add_settings_script(o: &mut String) {
+fn add_converter_script(o: &mut dyn Write) -> io::Result<()> {
     w!(o, 2, "<script type='text/javascript'>");
 
-    wnonl!(o, 0, "{}", include_str!("js/settings.js").indent_lines(3));
+    wnonl!(o, 0, "{}", include_str!("js/converter.js").indent_lines(3));
 
     w!(o, 2, "</script>");
+
+    Ok(())
 }
 
-fn add_scripts(o: &mut String, elf: &ParsedElf) {
-    add_highlight_script(o);
+fn add_search_script(o: &mut dyn Write, elf: &ParsedElf) -> io::Result<()> {
+    let big_endian = elf.contents[ELF_EI_DATA as usize] == ELF_DATA2MSB;
 
-    add_description_script(o);
+    w!(o, 2, "<script type='text/javascript'>");
 
-    add_conceal_script(o);
+    w!(o, 3, "let bigEndian = {};", big_endian);
 
-    add_offsets_script(o, elf);
+    wnonl!(o, 0, "{}", include_str!("js/search.js").indent_lines(3));
 
-    add_arrows_script(o, elf);
+    w!(o, 2, "</script>");
 
-    add_settings_script(o);
+    Ok(())
 }
 
-fn format_magic(byte: u8) -> String {
-    if byte.is_ascii_graphic() {
-        format!("&nbsp;{}", char::from(byte))
-    } else {
-        format!("{:02x}", byte)
+// String literal which is safe to put into a script element
+fn js_string(s: &str) -> String {
+    let mut result = String::from("\"");
+
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '<' => result.push_str("\\u003c"),
+            c if c.is_control() => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
     }
+
+    result.push('"');
+    result
 }
 
-fn digit_to_hex(digit: u8) -> char {
-    [
-        '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e', 'f',
-    ][digit as usize]
+fn add_links_script(o: &mut dyn Write, elf: &ParsedElf) -> io::Result<()> {
+    let names: Vec<String> = elf
+        .shdrs
+        .iter()
+        .map(|shdr| js_string(elf.shnstrtab.get(shdr.name)))
+        .collect();
+
+    w!(o, 2, "<script type='text/javascript'>");
+
+    w!(o, 3, "let sectionNames = [{}];", names.join(", "));
+
+    wnonl!(o, 0, "{}", include_str!("js/links.js").indent_lines(3));
+
+    w!(o, 2, "</script>");
+
+    Ok(())
 }
 
-fn append_hex_byte(s: &mut String, byte: u8) {
-    if byte < 0x10 {
-        s.push('0');
+fn add_vmap_script(o: &mut dyn Write, elf: &ParsedElf) -> io::Result<()> {
+    // there's no memory map without PT_LOAD segments, see generate_vmap()
+    if elf.load_segments().next().is_none() {
+        return Ok(());
+    }
 
-        s.push(digit_to_hex(byte));
-    } else {
-        let trailing_digit = byte % 16;
-        let leading_digit = byte / 16;
+    w!(o, 2, "<script type='text/javascript'>");
+
+    wnonl!(o, 0, "{}", include_str!("js/vmap.js").indent_lines(3));
+
+    w!(o, 2, "</script>");
+
+    Ok(())
+}
+
+fn add_settings_script(o: &mut dyn Write) -> io::Result<()> {
+    w!(o, 2, "<script type='text/javascript'>");
 
-        s.push(digit_to_hex(leading_digit));
-        s.push(digit_to_hex(trailing_digit));
+    wnonl!(o, 0, "{}", include_str!("js/settings.js").indent_lines(3));
+
+    w!(o, 2, "</script>");
+
+    Ok(())
+}
+
+fn add_scripts(
+    o: &mut dyn Write,
+    elf: &ParsedElf,
+    options: &ReportOptions,
+    regions: &[(usize, usize)],
+    range_count: usize,
+) -> io::Result<()> {
+    // the other scripts rely on the dump being set up
+    add_dump_script(o, elf, options, regions, range_count)?;
+
+    add_highlight_script(o)?;
+
+    add_description_script(o)?;
+
+    add_conceal_script(o)?;
+
+    add_arrows_script(o, elf, options)?;
+
+    add_search_script(o, elf)?;
+
+    add_links_script(o, elf)?;
+
+    add_converter_script(o)?;
+
+    add_vmap_script(o, elf)?;
+
+    add_settings_script(o)?;
+
+    Ok(())
+}
+
+// Encodes everything written to it as base64 in lines of equal length, which lets dump.js decode
+// the data in chunks
+struct Base64Lines<'a> {
+    o: &'a mut dyn Write,
+    pending: Vec<u8>,
+}
+
+impl Base64Lines<'_> {
+    fn new(o: &mut dyn Write) -> Base64Lines<'_> {
+        Base64Lines { o, pending: vec![] }
+    }
+
+    fn write_lines(&mut self, bytes: &[u8]) -> io::Result<()> {
+        let mut text = String::new();
+
+        for line in bytes.chunks(BASE64_LINE_BYTES) {
+            utils::append_base64(&mut text, line);
+            text.push('\n');
+        }
+
+        self.o.write_all(text.as_bytes())
+    }
+
+    fn finish(mut self) -> io::Result<()> {
+        let pending = std::mem::take(&mut self.pending);
+
+        self.write_lines(&pending)
     }
 }
 
-fn generate_file_dump(elf: &ParsedElf) -> String {
-    let mut dump = String::new();
+impl Write for Base64Lines<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.pending.extend_from_slice(buf);
 
-    for idx in 0..elf.contents.len() {
-        let byte = elf.contents[idx];
+        let full = self.pending.len() / BASE64_LINE_BYTES * BASE64_LINE_BYTES;
 
-        for range_type in &elf.ranges.data[idx] {
-            if *range_type != RangeType::End {
-                dump.push_str(format!("<span {}>", range_type.span_attributes()).as_str());
-            }
+        if full >= BASE64_BATCH_SIZE {
+            let batch: Vec<u8> = self.pending.drain(..full).collect();
+
+            self.write_lines(&batch)?;
         }
 
-        if idx < 4 {
-            dump.push_str(&format_magic(byte));
-        } else {
-            append_hex_byte(&mut dump, byte);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.o.flush()
+    }
+}
+
+// Parts of the file included in the dump as sorted [start, end) offsets, which start at row
+// boundaries where possible
+fn dump_regions(elf: &ParsedElf, options: &ReportOptions) -> Vec<(usize, usize)> {
+    let sections = match &options.dump {
+        DumpContents::Everything => return vec![(0, elf.file_size)],
+        DumpContents::HeadersAnd(sections) => sections,
+    };
+
+    let mut wanted = vec![];
+
+    for (start, len, range_type) in elf.ranges.iter() {
+        if let RangeType::FileHeader | RangeType::ProgramHeader(_) | RangeType::SectionHeader(_) =
+            range_type
+        {
+            wanted.push((start, start + len));
         }
+    }
+
+    for shdr in &elf.shdrs {
+        let name = elf.shnstrtab.get(shdr.name);
 
-        for _ in 0..elf.ranges.lookup_range_ends(idx) {
-            dump.push_str("</span>");
+        if shdr.shtype == SHT_NOBITS || !sections.iter().any(|section| section == name) {
+            continue;
         }
 
-        if idx != elf.contents.len() - 1 {
-            dump.push_str(if (idx + 1) % DEFAULT_COLUMNS == 0 { "\n" } else { " " });
+        if let Some(bytes) = file_slice(elf.contents, shdr.file_offset, shdr.size) {
+            wanted.push((shdr.file_offset, shdr.file_offset + bytes.len()));
         }
     }
 
-    dump
-}
+    wanted.sort_unstable();
 
-fn generate_ascii_dump(o: &mut String, elf: &ParsedElf) {
-    for (i, b) in elf.contents.iter().enumerate() {
-        if b.is_ascii_graphic() {
-            let ch = *b as char;
+    let mut regions: Vec<(usize, usize)> = vec![];
 
-            if let Some(escaped) = utils::html_escape(ch) {
-                wnonl!(o, 0, "{}", escaped);
-            } else {
-                wnonl!(o, 0, "{}", ch);
+    let columns = options.columns.get();
+
+    for (start, end) in wanted {
+        let start = start - start % columns;
+        let end = end
+            .div_ceil(columns)
+            .saturating_mul(columns)
+            .min(elf.file_size);
+
+        match regions.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => regions.push((start, end)),
+        }
+    }
+
+    regions
+}
+
+fn generate_file_data(
+    o: &mut dyn Write,
+    elf: &ParsedElf,
+    options: &ReportOptions,
+    regions: &[(usize, usize)],
+) -> io::Result<()> {
+    let total: usize = regions.iter().map(|(start, end)| end - start).sum();
+    let show_progress = options.progress && total >= PROGRESS_MIN_FILE_SIZE;
+    let mut data = Base64Lines::new(o);
+    let mut done = 0;
+
+    for &(start, end) in regions {
+        for chunk in elf.contents[start..end].chunks(PROGRESS_STEP) {
+            data.write_all(chunk)?;
+
+            done += chunk.len();
+
+            if show_progress {
+                eprint!("\rGenerating hex dump: {}%", done * 100 / total);
             }
-        } else {
-            wnonl!(o, 0, ".");
         }
+    }
+
+    if show_progress {
+        eprintln!();
+    }
 
-        if (i + 1) % DEFAULT_COLUMNS == 0 {
-            w!(o, 0, "");
+    data.finish()
+}
+
+// Each range is a little-endian 64-bit start and length, and a 32-bit index into the list of span
+// classes, which is returned. Ranges outside of the dumped regions are left out.
+fn generate_range_data(
+    o: &mut dyn Write,
+    elf: &ParsedElf,
+    regions: &[(usize, usize)],
+) -> io::Result<(usize, Vec<String>)> {
+    let mut count = 0;
+    let mut classes = vec![];
+    let mut class_indices = HashMap::new();
+    let mut data = Base64Lines::new(o);
+
+    for (start, len, range_type) in elf.ranges.iter() {
+        let region = regions.partition_point(|&(_, end)| end <= start);
+
+        if regions
+            .get(region)
+            .is_none_or(|&(region_start, _)| region_start >= start + len)
+        {
+            continue;
         }
+
+        let span_classes = range_type.span_classes();
+        let class_idx = *class_indices
+            .entry(span_classes)
+            .or_insert_with_key(|span_classes| {
+                classes.push(span_classes.clone());
+                classes.len() - 1
+            });
+
+        data.write_all(&(start as u64).to_le_bytes())?;
+        data.write_all(&(len as u64).to_le_bytes())?;
+        data.write_all(&(class_idx as u32).to_le_bytes())?;
+
+        count += 1;
+    }
+
+    data.finish()?;
+
+    Ok((count, classes))
+}
+
+// The hex dump is rendered by dump.js, only rows which are visible at the moment. The file
+// contents and ranges are embedded as data blocks for it.
+fn generate_dump_data(
+    o: &mut dyn Write,
+    elf: &ParsedElf,
+    options: &ReportOptions,
+    regions: &[(usize, usize)],
+) -> io::Result<usize> {
+    wnonl!(o, 2, "<script type='text/plain' id='file_data'>");
+    generate_file_data(o, elf, options, regions)?;
+    w!(o, 0, "</script>");
+
+    wnonl!(o, 2, "<script type='text/plain' id='range_data'>");
+    let (range_count, classes) = generate_range_data(o, elf, regions)?;
+    w!(o, 0, "</script>");
+
+    wnonl!(o, 2, "<script type='text/plain' id='range_classes'>");
+    for class in classes {
+        w!(o, 0, "{}", class);
     }
+    w!(o, 0, "</script>");
+
+    Ok(range_count)
 }
 
-fn generate_body(o: &mut String, elf: &ParsedElf) {
+fn generate_body(o: &mut dyn Write, elf: &ParsedElf, options: &ReportOptions) -> io::Result<()> {
     w!(o, 1, "<body>");
 
-    generate_svg_element(o);
+    generate_svg_element(o)?;
 
     w!(o, 2, "<div id='rightmenu'>");
-    generate_right_menu(o);
+    generate_right_menu(o, elf, options)?;
     w!(o, 2, "</div>");
 
-    generate_file_info_table(o, elf);
+    generate_search_panel(o)?;
 
-    wnonl!(o, 2, "<div id='offsets'>");
-    for off in (0..elf.contents.len()).step_by(DEFAULT_COLUMNS) {
-        wnonl!(o, 0, "{:x}", off);
-        if off != (elf.contents.len() / DEFAULT_COLUMNS) * DEFAULT_COLUMNS {
-            w!(o, 0, "");
-        }
-    }
-    w!(o, 0, "</div>");
+    generate_file_info_table(o, elf)?;
+
+    generate_problems_table(o, elf)?;
+
+    w!(o, 2, "<div id='offsets'></div><div id='bytes'></div><div id='ascii'></div>");
 
-    wnonl!(o, 2, "<div id='bytes'>");
-    wnonl!(o, 0, "{}", generate_file_dump(elf));
-    w!(o, 0, "</div>");
+    let regions = dump_regions(elf, options);
+    let range_count = generate_dump_data(o, elf, options, &regions)?;
 
-    wnonl!(o, 2, "<div id='ascii'>");
-    generate_ascii_dump(o, elf);
-    w!(o, 0, "</div>");
+    generate_vmap(o, elf)?;
 
-    generate_sticky_info_tables(o, elf);
+    generate_sticky_info_tables(o, elf)?;
 
-    add_scripts(o, elf);
+    add_scripts(o, elf, options, &regions, range_count)?;
 
     w!(o, 1, "</body>");
+
+    Ok(())
 }
 
 pub fn generate_report(elf: &ParsedElf) -> String {
-    let mut output = String::new();
+    generate_report_with_options(elf, &ReportOptions::default())
+}
+
+pub fn generate_report_with_options(elf: &ParsedElf, options: &ReportOptions) -> String {
+    let mut output = vec![];
+
+    // writing into a Vec can't fail, and everything written is built from strings
+    write_report(&mut output, elf, options).expect("write report to memory");
+
+    String::from_utf8(output).expect("report is valid UTF-8")
+}
 
-    w!(&mut output, 0, "<!doctype html>");
-    w!(&mut output, 0, "<html>");
+pub fn write_report(o: &mut dyn Write, elf: &ParsedElf, options: &ReportOptions) -> io::Result<()> {
+    w!(o, 0, "<!doctype html>");
+    w!(o, 0, "<html>");
 
-    generate_head(&mut output, elf);
-    generate_body(&mut output, elf);
+    generate_head(o, elf, options)?;
+    generate_body(o, elf, options)?;
 
-    w!(&mut output, 0, "</html>");
+    w!(o, 0, "</html>");
 
-    output
+    o.flush()
 }
@@ -0,0 +1,617 @@
+// Plain-text rendering of what the infotables of the HTML report show, for terminals
+
+use crate::elf::defs::*;
+use crate::elf::parser::{
+    file_slice, Note, ParsedDyn, ParsedElf, ParsedHashTab, ParsedRelTab, ParsedShdr, ParsedSymTab,
+};
+use std::io::{self, Write};
+
+const INDENT: &str = "  ";
+const COLUMN_GAP: &str = "  ";
+
+// Table with columns padded to the widest cell
+struct Table {
+    columns: Vec<&'static str>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    fn new(columns: &[&'static str]) -> Table {
+        Table {
+            columns: columns.to_vec(),
+            rows: vec![],
+        }
+    }
+
+    fn push(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    fn write(&self, o: &mut dyn Write) -> io::Result<()> {
+        let mut widths: Vec<usize> = self.columns.iter().map(|c| c.len()).collect();
+
+        for row in &self.rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let header: Vec<String> = self.columns.iter().map(|c| c.to_string()).collect();
+        let rule: Vec<String> = widths.iter().map(|&w| "-".repeat(w)).collect();
+
+        write_table_row(o, &widths, &header)?;
+        write_table_row(o, &widths, &rule)?;
+
+        for row in &self.rows {
+            write_table_row(o, &widths, row)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn write_table_row(o: &mut dyn Write, widths: &[usize], row: &[String]) -> io::Result<()> {
+    let mut line = String::from(INDENT);
+
+    for (i, cell) in row.iter().enumerate() {
+        if i > 0 {
+            line.push_str(COLUMN_GAP);
+        }
+
+        line.push_str(cell);
+        line.push_str(&" ".repeat(widths[i].saturating_sub(cell.chars().count())));
+    }
+
+    writeln!(o, "{}", line.trim_end())
+}
+
+// Rows of "label: value" with values aligned
+fn write_fields(o: &mut dyn Write, fields: &[(&str, String)]) -> io::Result<()> {
+    let width = fields
+        .iter()
+        .map(|(label, _)| label.len())
+        .max()
+        .unwrap_or(0);
+
+    for (label, value) in fields {
+        writeln!(o, "{}{:width$}  {}", INDENT, format!("{}:", label), value, width = width + 1)?;
+    }
+
+    Ok(())
+}
+
+fn write_heading(o: &mut dyn Write, heading: &str) -> io::Result<()> {
+    writeln!(o)?;
+    writeln!(o, "{}", heading)?;
+    writeln!(o)
+}
+
+// Names in files can contain anything, including terminal escape sequences
+fn printable(s: &str) -> String {
+    s.chars()
+        .map(|ch| {
+            if ch.is_control() {
+                ch.escape_default().to_string()
+            } else {
+                ch.to_string()
+            }
+        })
+        .collect()
+}
+
+fn section_heading(elf: &ParsedElf, idx: usize, shdr: &ParsedShdr) -> String {
+    format!(
+        "Section {} {} ({})",
+        idx,
+        printable(elf.section_name(idx)),
+        shtype_to_string(shdr.shtype)
+    )
+}
+
+fn write_file_info(o: &mut dyn Write, elf: &ParsedElf) -> io::Result<()> {
+    let fields: Vec<(&str, String)> = elf
+        .information
+        .iter()
+        .map(|(_, desc, value)| (*desc, printable(value)))
+        .collect();
+
+    writeln!(o, "File information")?;
+    writeln!(o)?;
+
+    write_fields(o, &fields)
+}
+
+fn write_problems(o: &mut dyn Write, elf: &ParsedElf) -> io::Result<()> {
+    if elf.warnings.is_empty() {
+        return Ok(());
+    }
+
+    let mut table = Table::new(&["Kind", "Structure", "Offset", "Detail"]);
+
+    for warning in &elf.warnings {
+        let offset = match warning.offset {
+            Some(offset) => format!("{:#x}", offset),
+            None => String::new(),
+        };
+
+        table.push(vec![
+            warning.kind.to_string(),
+            printable(&warning.structure),
+            offset,
+            printable(&warning.detail),
+        ]);
+    }
+
+    write_heading(o, "Problems")?;
+
+    table.write(o)
+}
+
+fn write_phdrs(o: &mut dyn Write, elf: &ParsedElf) -> io::Result<()> {
+    if elf.phdrs.is_empty() {
+        return Ok(());
+    }
+
+    let columns = [
+        "Num",
+        "Type",
+        "Flags",
+        "Offset",
+        "Size in file",
+        "Vaddr",
+        "Size in memory",
+        "Alignment",
+    ];
+    let mut table = Table::new(&columns);
+
+    for (idx, phdr) in elf.phdrs.iter().enumerate() {
+        table.push(vec![
+            idx.to_string(),
+            ptype_to_string(phdr.ptype),
+            phdr.flags.clone(),
+            format!("{:#x}", phdr.file_offset),
+            phdr.file_size.to_string(),
+            format!("{:#x}", phdr.vaddr),
+            phdr.memsz.to_string(),
+            format!("{:#x}", phdr.alignment),
+        ]);
+    }
+
+    write_heading(o, "Program headers")?;
+
+    table.write(o)
+}
+
+fn write_shdrs(o: &mut dyn Write, elf: &ParsedElf) -> io::Result<()> {
+    if elf.shdrs.is_empty() {
+        return Ok(());
+    }
+
+    let columns = [
+        "Num",
+        "Name",
+        "Type",
+        "Flags",
+        "Vaddr",
+        "Offset",
+        "Size",
+        "Link",
+        "Info",
+        "Alignment",
+        "Entry size",
+    ];
+    let mut table = Table::new(&columns);
+
+    for (idx, shdr) in elf.shdrs.iter().enumerate() {
+        table.push(vec![
+            idx.to_string(),
+            printable(elf.shnstrtab.get(shdr.name)),
+            shtype_to_string(shdr.shtype),
+            shflags_to_string(shdr.flags),
+            format!("{:#x}", shdr.addr),
+            format!("{:#x}", shdr.file_offset),
+            shdr.size.to_string(),
+            shdr.link.to_string(),
+            shdr.info.to_string(),
+            format!("{:#x}", shdr.addralign),
+            shdr.entsize.to_string(),
+        ]);
+    }
+
+    write_heading(o, "Section headers")?;
+
+    table.write(o)
+}
+
+// Like readelf's section to segment mapping
+fn write_segment_sections(o: &mut dyn Write, elf: &ParsedElf) -> io::Result<()> {
+    if elf.phdrs.is_empty() || elf.shdrs.is_empty() {
+        return Ok(());
+    }
+
+    let mut table = Table::new(&["Segment", "Type", "Sections"]);
+
+    for (idx, sections) in elf.segment_sections.iter().enumerate() {
+        let names: Vec<String> = sections
+            .iter()
+            .map(|&section| printable(elf.section_name(section)))
+            .collect();
+
+        table.push(vec![
+            idx.to_string(),
+            ptype_to_string(elf.phdrs[idx].ptype),
+            names.join(" "),
+        ]);
+    }
+
+    write_heading(o, "Section to segment mapping")?;
+
+    table.write(o)
+}
+
+fn write_segment_details(o: &mut dyn Write, elf: &ParsedElf) -> io::Result<()> {
+    for (idx, phdr) in elf.phdrs.iter().enumerate() {
+        // notes and the dynamic section have sections of their own below
+        if phdr.ptype != PT_INTERP {
+            continue;
+        }
+
+        let interp = file_slice(elf.contents, phdr.file_offset, phdr.file_size).unwrap_or(&[]);
+        let interp = interp.strip_suffix(&[0]).unwrap_or(interp);
+
+        write_heading(o, &format!("Segment {} ({})", idx, ptype_to_string(phdr.ptype)))?;
+
+        write_fields(o, &[("Interpreter", interp.escape_ascii().to_string())])?;
+    }
+
+    Ok(())
+}
+
+fn format_dyn_value(entry: &ParsedDyn) -> String {
+    if let Some(string) = &entry.string {
+        return printable(string);
+    }
+
+    match entry.tag {
+        DT_FLAGS => dflags_to_string(entry.value),
+        DT_FLAGS_1 => dflags1_to_string(entry.value),
+        DT_PLTREL => dtag_to_string(entry.value as i64),
+        tag if dtag_is_address(tag) => match entry.file_offset {
+            Some(offset) => format!("{:#x} (offset {:#x})", entry.value, offset),
+            None => format!("{:#x}", entry.value),
+        },
+        _ => entry.value.to_string(),
+    }
+}
+
+fn write_dynamic(o: &mut dyn Write, elf: &ParsedElf) -> io::Result<()> {
+    if elf.dynamic.is_empty() {
+        return Ok(());
+    }
+
+    let mut table = Table::new(&["Tag", "Value"]);
+
+    for entry in &elf.dynamic {
+        table.push(vec![dtag_to_string(entry.tag), format_dyn_value(entry)]);
+    }
+
+    write_heading(o, "Dynamic section")?;
+
+    table.write(o)
+}
+
+fn write_strtab(o: &mut dyn Write, section: &[u8]) -> io::Result<()> {
+    let mut table = Table::new(&["Offset", "String"]);
+    let mut offset = 0;
+
+    for string in section.split(|&b| b == 0) {
+        if !string.is_empty() {
+            table.push(vec![
+                format!("{:#x}", offset),
+                printable(&String::from_utf8_lossy(string)),
+            ]);
+        }
+
+        offset += string.len() + 1;
+    }
+
+    table.write(o)
+}
+
+fn write_symtab(o: &mut dyn Write, symtab: &ParsedSymTab) -> io::Result<()> {
+    let columns = ["Num", "Value", "Size", "Type", "Bind", "Vis", "Ndx", "Name"];
+    let mut table = Table::new(&columns);
+
+    for (idx, sym) in symtab.syms.iter().enumerate() {
+        table.push(vec![
+            idx.to_string(),
+            format!("{:#x}", sym.value),
+            sym.size.to_string(),
+            symtype_to_string(sym.stype),
+            symbind_to_string(sym.bind),
+            symvis_to_string(sym.visibility),
+            sym.shndx_string(),
+            printable(&sym.name_with_version()),
+        ]);
+    }
+
+    table.write(o)
+}
+
+fn write_reltab(
+    o: &mut dyn Write,
+    elf: &ParsedElf,
+    shdr: &ParsedShdr,
+    reltab: &ParsedRelTab,
+) -> io::Result<()> {
+    let symtab = elf
+        .symtabs
+        .iter()
+        .find(|symtab| symtab.section == shdr.link);
+    let mut fields = vec![];
+
+    if shdr.info != 0 {
+        fields.push(("Applies to", printable(elf.section_name(shdr.info))));
+    }
+
+    fields.push(("Symbol table", printable(elf.section_name(shdr.link))));
+
+    write_fields(o, &fields)?;
+    writeln!(o)?;
+
+    let columns: &[&str] = if shdr.shtype == SHT_RELA {
+        &["Offset", "Type", "Sym", "Symbol name", "Addend"]
+    } else {
+        &["Offset", "Type", "Sym", "Symbol name"]
+    };
+    let mut table = Table::new(columns);
+
+    for rel in &reltab.rels {
+        let mut row = vec![
+            format!("{:#x}", rel.offset),
+            reltype_to_string(elf.machine, rel.rtype),
+            rel.sym.to_string(),
+            printable(elf.rel_symbol_name(symtab, rel.sym)),
+        ];
+
+        if let Some(addend) = rel.addend {
+            row.push(addend.to_string());
+        }
+
+        table.push(row);
+    }
+
+    table.write(o)
+}
+
+fn write_verneed(o: &mut dyn Write, elf: &ParsedElf) -> io::Result<()> {
+    let columns = ["File", "Version", "Hash", "Flags", "Index", "Symbols"];
+    let mut table = Table::new(&columns);
+
+    for verneed in &elf.verneeds {
+        for (i, vernaux) in verneed.aux.iter().enumerate() {
+            let file = if i == 0 {
+                printable(&verneed.file)
+            } else {
+                String::new()
+            };
+
+            table.push(vec![
+                file,
+                printable(&vernaux.name),
+                format!("{:#010x}", vernaux.hash),
+                verflags_to_string(vernaux.flags),
+                vernaux.other.to_string(),
+                printable(&elf.symbols_with_version(vernaux.other).join(" ")),
+            ]);
+        }
+    }
+
+    table.write(o)
+}
+
+fn write_verdef(o: &mut dyn Write, elf: &ParsedElf) -> io::Result<()> {
+    let mut table = Table::new(&["Index", "Flags", "Version", "Hash", "Parents"]);
+
+    for verdef in &elf.verdefs {
+        let mut names = verdef.aux.iter().map(|aux| printable(&aux.name));
+        let name = names.next().unwrap_or_default();
+        let parents: Vec<String> = names.collect();
+
+        table.push(vec![
+            verdef.ndx.to_string(),
+            verflags_to_string(verdef.flags),
+            name,
+            format!("{:#010x}", verdef.hash),
+            parents.join(" "),
+        ]);
+    }
+
+    table.write(o)
+}
+
+fn write_versym(o: &mut dyn Write, elf: &ParsedElf, shdr: &ParsedShdr) -> io::Result<()> {
+    let symtab = match elf
+        .symtabs
+        .iter()
+        .find(|symtab| symtab.section == shdr.link)
+    {
+        Some(symtab) => symtab,
+        None => return Ok(()),
+    };
+    let mut table = Table::new(&["Num", "Versym", "Symbol"]);
+
+    for (idx, sym) in symtab.syms.iter().enumerate() {
+        if let Some(versym) = sym.versym {
+            table.push(vec![
+                idx.to_string(),
+                format!("{:#x}", versym),
+                printable(&sym.name_with_version()),
+            ]);
+        }
+    }
+
+    table.write(o)
+}
+
+fn write_hashtab(
+    o: &mut dyn Write,
+    elf: &ParsedElf,
+    shdr: &ParsedShdr,
+    hashtab: &ParsedHashTab,
+) -> io::Result<()> {
+    let mut fields = vec![
+        ("Symbol table", printable(elf.section_name(shdr.link))),
+        ("Buckets", hashtab.nbuckets.to_string()),
+    ];
+
+    if hashtab.gnu {
+        fields.push(("Symbol offset", hashtab.symoffset.to_string()));
+        fields.push(("Bloom words", hashtab.bloom_size.to_string()));
+        fields.push(("Bloom shift", hashtab.bloom_shift.to_string()));
+    }
+
+    for problem in &hashtab.problems {
+        fields.push(("Problem(!)", printable(problem)));
+    }
+
+    if hashtab.chain_lengths.is_empty() {
+        return write_fields(o, &fields);
+    }
+
+    let nbuckets = hashtab.chain_lengths.len();
+    let nsyms: usize = hashtab.chain_lengths.iter().sum();
+    let counts = hashtab.chain_length_counts();
+    let empty = counts[0];
+
+    fields.push(("Hashed symbols", nsyms.to_string()));
+    fields.push((
+        "Empty buckets",
+        format!("{} ({:.1}%)", empty, 100.0 * empty as f64 / nbuckets as f64),
+    ));
+    fields.push(("Max chain length", (counts.len() - 1).to_string()));
+
+    if hashtab.unreachable.is_empty() {
+        fields.push(("Lookup check", "all exported symbols are reachable".to_string()));
+    } else {
+        fields.push(("Unreachable(!)", printable(&hashtab.unreachable.join(" "))));
+    }
+
+    write_fields(o, &fields)?;
+    writeln!(o)?;
+
+    let mut table = Table::new(&["Length", "Buckets", "% of total", "Coverage"]);
+    let mut covered = 0;
+
+    for (len, &count) in counts.iter().enumerate() {
+        covered += len * count;
+
+        table.push(vec![
+            len.to_string(),
+            count.to_string(),
+            format!("{:.1}%", 100.0 * count as f64 / nbuckets as f64),
+            format!("{:.1}%", 100.0 * covered as f64 / nsyms.max(1) as f64),
+        ]);
+    }
+
+    table.write(o)
+}
+
+fn write_section_details(o: &mut dyn Write, elf: &ParsedElf) -> io::Result<()> {
+    for (idx, shdr) in elf.shdrs.iter().enumerate() {
+        match shdr.shtype {
+            SHT_STRTAB => {
+                write_heading(o, &section_heading(elf, idx, shdr))?;
+                write_strtab(o, elf.section_bytes(idx))?;
+            }
+            SHT_SYMTAB | SHT_DYNSYM => {
+                if let Some(symtab) = elf.symtabs.iter().find(|symtab| symtab.section == idx) {
+                    write_heading(o, &section_heading(elf, idx, shdr))?;
+                    write_symtab(o, symtab)?;
+                }
+            }
+            SHT_VER_NEED => {
+                write_heading(o, &section_heading(elf, idx, shdr))?;
+                write_verneed(o, elf)?;
+            }
+            SHT_VER_DEF => {
+                write_heading(o, &section_heading(elf, idx, shdr))?;
+                write_verdef(o, elf)?;
+            }
+            SHT_VER_SYM => {
+                write_heading(o, &section_heading(elf, idx, shdr))?;
+                write_versym(o, elf, shdr)?;
+            }
+            SHT_HASH | SHT_GNU_HASH => {
+                if let Some(hashtab) = elf.hashtabs.iter().find(|hashtab| hashtab.section == idx) {
+                    write_heading(o, &section_heading(elf, idx, shdr))?;
+                    write_hashtab(o, elf, shdr, hashtab)?;
+                }
+            }
+            SHT_REL | SHT_RELA => {
+                if let Some(reltab) = elf.reltabs.iter().find(|reltab| reltab.section == idx) {
+                    write_heading(o, &section_heading(elf, idx, shdr))?;
+                    write_reltab(o, elf, shdr, reltab)?;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn format_note_desc(note: &Note) -> String {
+    if note.ntype == NT_GNU_BUILD_ID {
+        let hash: String = note.desc.iter().map(|b| format!("{:02x}", b)).collect();
+
+        format!("Build ID: {}", hash)
+    } else {
+        note.desc.escape_ascii().to_string()
+    }
+}
+
+fn write_notes(o: &mut dyn Write, elf: &ParsedElf) -> io::Result<()> {
+    if elf.notes.is_empty() {
+        return Ok(());
+    }
+
+    let mut table = Table::new(&["Name", "Type", "Desc"]);
+
+    for note in &elf.notes {
+        let name = note.name.strip_suffix(&[0]).unwrap_or(&note.name);
+
+        table.push(vec![
+            name.escape_ascii().to_string(),
+            format!("{:#x}", note.ntype),
+            format_note_desc(note),
+        ]);
+    }
+
+    write_heading(o, "Notes")?;
+
+    table.write(o)
+}
+
+pub fn generate_text(elf: &ParsedElf) -> String {
+    let mut output = vec![];
+
+    // same as with generate_report_with_options(), writing into a Vec can't fail
+    write_text(&mut output, elf).expect("write text to memory");
+
+    String::from_utf8(output).expect("text is valid UTF-8")
+}
+
+pub fn write_text(o: &mut dyn Write, elf: &ParsedElf) -> io::Result<()> {
+    write_file_info(o, elf)?;
+    write_problems(o, elf)?;
+    write_phdrs(o, elf)?;
+    write_shdrs(o, elf)?;
+    write_segment_sections(o, elf)?;
+    write_segment_details(o, elf)?;
+    write_dynamic(o, elf)?;
+    write_section_details(o, elf)?;
+    write_notes(o, elf)?;
+
+    o.flush()
+}
@@ -28,46 +28,61 @@ pub fn html_escape(ch: char) -> Option<&'static str> {
     }
 }
 
-pub trait MaybeError<T> {
-    fn or_exit(self, message: &str) -> T;
-}
+pub fn html_escape_str(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
 
-impl<T, E> MaybeError<T> for Result<T, E>
-where
-    E: std::fmt::Display,
-{
-    fn or_exit(self, message: &str) -> T {
-        match self {
-            Ok(t) => t,
-            Err(e) => {
-                eprintln!("Failed to {}: {}", message, e);
-                std::process::exit(1);
-            }
+    for ch in s.chars() {
+        match html_escape(ch) {
+            Some(entity) => escaped.push_str(entity),
+            None => escaped.push(ch),
         }
     }
+
+    escaped
 }
 
-impl<T> MaybeError<T> for Option<T> {
-    fn or_exit(self, message: &str) -> T {
-        if self.is_none() {
-            eprintln!("Failed to {}", message);
-            std::process::exit(1);
-        }
+fn digit_to_hex(digit: u8) -> char {
+    [
+        '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e', 'f',
+    ][digit as usize]
+}
 
-        self.unwrap()
+pub fn append_hex_byte(s: &mut String, byte: u8) {
+    if byte < 0x10 {
+        s.push('0');
+
+        s.push(digit_to_hex(byte));
+    } else {
+        let trailing_digit = byte % 16;
+        let leading_digit = byte / 16;
+
+        s.push(digit_to_hex(leading_digit));
+        s.push(digit_to_hex(trailing_digit));
     }
 }
 
-pub fn basename(path: &str) -> Option<&str> {
-    Path::new(path).file_name()?.to_str()
-}
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
 
-fn stem(path: &str) -> Option<&str> {
-    Path::new(path).file_stem()?.to_str()
-}
+pub fn append_base64(s: &mut String, bytes: &[u8]) {
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = (b[0] as usize) << 16 | (b[1] as usize) << 8 | b[2] as usize;
 
-pub fn construct_filename(filename: &str) -> Option<String> {
-    let name = stem(basename(filename)?)?.to_string() + ".html";
+        for i in 0..4 {
+            if i <= chunk.len() {
+                s.push(BASE64_ALPHABET[(n >> (18 - 6 * i)) & 0x3f] as char);
+            } else {
+                s.push('=');
+            }
+        }
+    }
+}
 
-    Some(name)
+pub fn basename(path: &str) -> Option<&str> {
+    Path::new(path).file_name()?.to_str()
 }